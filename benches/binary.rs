@@ -0,0 +1,33 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+pub fn binary(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Binary");
+
+    for i in [
+        "2019.01.06",
+        "2019.01.06.1",
+        "2019.01.06-test",
+        "2019.01.06.1-test",
+        "2019.01.06.1-test.1",
+    ]
+    .iter()
+    {
+        let version = chronver::Version::parse(i).unwrap();
+        let encoded = version.encode();
+
+        group.bench_with_input(BenchmarkId::new("Encode", i), &version, |b, v| {
+            b.iter(|| black_box(v).encode())
+        });
+        group.bench_with_input(BenchmarkId::new("Decode", i), &encoded, |b, e| {
+            b.iter(|| chronver::Version::decode(black_box(e)))
+        });
+        group.bench_with_input(BenchmarkId::new("Parse", i), i, |b, i| {
+            b.iter(|| chronver::Version::parse(black_box(i)))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, binary);
+criterion_main!(benches);
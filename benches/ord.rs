@@ -0,0 +1,51 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+
+/// Create a shuffled list of versions, spread over a few years with some changesets and labels
+/// sharing the same date.
+fn versions(count: u32) -> Vec<chronver::Version> {
+    let mut state = 0x2545_f491_u32;
+    (0..count)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+
+            let day = 2_458_850 + (state % 2000) as i32;
+            let mut version =
+                chronver::Version::from(chronver::Date::from_julian_day(day).unwrap());
+            version.changeset = state % 3;
+            if state % 5 == 0 {
+                version.label = Some(chronver::Label::parse("feature.1"));
+            }
+            version
+        })
+        .collect()
+}
+
+pub fn ord(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Ord");
+
+    let list = versions(2);
+    group.bench_function("Compare", |b| {
+        b.iter(|| black_box(&list[0]).cmp(black_box(&list[1])))
+    });
+
+    for count in [1_000, 100_000] {
+        let list = versions(count);
+        group.bench_with_input(BenchmarkId::new("Sort", count), &list, |b, list| {
+            b.iter_batched(|| list.clone(), |mut l| l.sort(), BatchSize::LargeInput)
+        });
+        group.bench_with_input(BenchmarkId::new("SortUnstable", count), &list, |b, list| {
+            b.iter_batched(
+                || list.clone(),
+                |mut l| l.sort_unstable(),
+                BatchSize::LargeInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, ord);
+criterion_main!(benches);
@@ -0,0 +1,276 @@
+//! Compact binary encoding of versions, for fast transfer between services.
+//!
+//! The layout is fixed and all integers are stored in little-endian byte order:
+//!
+//! | Field     | Size     | Description                                           |
+//! | --------- | -------- | ----------------------------------------------------- |
+//! | year      | 4        | Signed year of the date.                              |
+//! | month     | 1        | Month of the date, `1` to `12`.                       |
+//! | day       | 1        | Day of the month.                                     |
+//! | changeset | 4        | Changeset number.                                     |
+//! | label tag | 1        | `0` for no label, `1` for a text, `2` for a feature.  |
+//! | epoch     | 4        | Epoch of the version (only if the epoch flag is set). |
+//! | length    | 4        | Length of the label text or branch (only if present). |
+//! | text      | variable | UTF-8 bytes of the label text or branch.              |
+//! | changeset | 4        | Changeset of a feature label (only for features).     |
+//!
+//! Versions of a non-zero epoch set the highest bit of the label tag as epoch flag, so the
+//! encoding of versions in the default epoch stays the same.
+
+use std::convert::TryInto;
+
+use time::{Date, Month};
+
+use crate::{ChronVerError, Label, Version};
+
+/// Tag for versions without label.
+const TAG_NONE: u8 = 0;
+/// Tag for text labels.
+const TAG_TEXT: u8 = 1;
+/// Tag for feature labels.
+const TAG_FEATURE: u8 = 2;
+/// Flag in the label tag, for versions with a non-zero epoch.
+const FLAG_EPOCH: u8 = 0x80;
+/// Size of the fixed part of the encoding (date, changeset and label tag).
+const FIXED_LENGTH: usize = 11;
+
+impl Version {
+    /// Get the amount of bytes this version takes up in the binary encoding.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1").unwrap();
+    /// assert_eq!(version.encoded_len(), version.encode().len());
+    /// ```
+    #[must_use]
+    pub fn encoded_len(&self) -> usize {
+        FIXED_LENGTH
+            + if self.epoch > 0 { 4 } else { 0 }
+            + match &self.label {
+                None => 0,
+                Some(Label::Text(text)) => 4 + text.len(),
+                Some(Label::Feature { branch, .. }) => 4 + branch.len() + 4,
+            }
+    }
+
+    /// Encode this version into its binary representation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1-test").unwrap();
+    /// let encoded = version.encode();
+    /// let (decoded, rest) = Version::decode(&encoded).unwrap();
+    ///
+    /// assert_eq!(decoded, version);
+    /// assert!(rest.is_empty());
+    /// ```
+    #[must_use]
+    pub fn encode(&self) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(self.encoded_len());
+        self.encode_into(&mut buf);
+        buf
+    }
+
+    /// Append the binary representation of this version to the given buffer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let mut buf = Vec::new();
+    /// Version::parse("2020.01.06").unwrap().encode_into(&mut buf);
+    /// Version::parse("2020.01.07").unwrap().encode_into(&mut buf);
+    ///
+    /// let (first, rest) = Version::decode(&buf).unwrap();
+    /// let (second, rest) = Version::decode(rest).unwrap();
+    ///
+    /// assert_eq!(first.to_string(), "2020.01.06");
+    /// assert_eq!(second.to_string(), "2020.01.07");
+    /// assert!(rest.is_empty());
+    /// ```
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn encode_into(&self, buf: &mut Vec<u8>) {
+        buf.reserve(self.encoded_len());
+        buf.extend_from_slice(&self.date.year().to_le_bytes());
+        buf.push(self.date.month() as u8);
+        buf.push(self.date.day());
+        buf.extend_from_slice(&self.changeset.to_le_bytes());
+
+        let tag = match &self.label {
+            None => TAG_NONE,
+            Some(Label::Text(_)) => TAG_TEXT,
+            Some(Label::Feature { .. }) => TAG_FEATURE,
+        };
+        if self.epoch > 0 {
+            buf.push(tag | FLAG_EPOCH);
+            buf.extend_from_slice(&self.epoch.to_le_bytes());
+        } else {
+            buf.push(tag);
+        }
+
+        match &self.label {
+            None => {}
+            Some(Label::Text(text)) => {
+                buf.extend_from_slice(&(text.len() as u32).to_le_bytes());
+                buf.extend_from_slice(text.as_bytes());
+            }
+            Some(Label::Feature { branch, changeset }) => {
+                buf.extend_from_slice(&(branch.len() as u32).to_le_bytes());
+                buf.extend_from_slice(branch.as_bytes());
+                buf.extend_from_slice(&changeset.to_le_bytes());
+            }
+        }
+    }
+
+    /// Decode a version from the start of the given bytes, returning the version together with
+    /// the remaining, unused bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1-feature.2").unwrap();
+    /// let mut buf = version.encode();
+    /// buf.push(42);
+    ///
+    /// let (decoded, rest) = Version::decode(&buf).unwrap();
+    /// assert_eq!(decoded, version);
+    /// assert_eq!(rest, [42]);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the bytes are too short, contain an invalid date or label tag or
+    /// the label is not valid UTF-8.
+    pub fn decode(bytes: &[u8]) -> Result<(Self, &[u8]), ChronVerError> {
+        let mut reader = Reader(bytes);
+
+        let year = i32::from_le_bytes(reader.take_array()?);
+        let month = Month::try_from(reader.take_u8()?)?;
+        let day = reader.take_u8()?;
+        let date = Date::from_calendar_date(year, month, day)?;
+        let changeset = reader.take_u32()?;
+
+        let tag = reader.take_u8()?;
+        let epoch = if tag & FLAG_EPOCH == 0 {
+            0
+        } else {
+            reader.take_u32()?
+        };
+
+        let label = match tag & !FLAG_EPOCH {
+            TAG_NONE => None,
+            TAG_TEXT => Some(Label::Text(reader.take_str()?.to_owned())),
+            TAG_FEATURE => Some(Label::Feature {
+                branch: reader.take_str()?.to_owned(),
+                changeset: reader.take_u32()?,
+            }),
+            _ => return Err(ChronVerError::InvalidBinary),
+        };
+
+        Ok((
+            Self {
+                epoch,
+                date,
+                changeset,
+                label,
+            },
+            reader.0,
+        ))
+    }
+}
+
+/// Simple cursor over a byte slice, that fails once it runs out of data.
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    /// Take the next `len` bytes.
+    fn take(&mut self, len: usize) -> Result<&'a [u8], ChronVerError> {
+        if self.0.len() < len {
+            return Err(ChronVerError::InvalidBinary);
+        }
+
+        let (value, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(value)
+    }
+
+    /// Take the next `N` bytes as fixed-size array.
+    fn take_array<const N: usize>(&mut self) -> Result<[u8; N], ChronVerError> {
+        self.take(N)
+            .map(|bytes| bytes.try_into().expect("length already checked"))
+    }
+
+    /// Take a single byte.
+    fn take_u8(&mut self) -> Result<u8, ChronVerError> {
+        self.take_array::<1>().map(|[value]| value)
+    }
+
+    /// Take a little-endian `u32`.
+    fn take_u32(&mut self) -> Result<u32, ChronVerError> {
+        self.take_array().map(u32::from_le_bytes)
+    }
+
+    /// Take a length-prefixed UTF-8 string.
+    fn take_str(&mut self) -> Result<&'a str, ChronVerError> {
+        let len = self.take_u32()? as usize;
+        std::str::from_utf8(self.take(len)?).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for input in [
+            "2019.01.06",
+            "2019.01.06.12",
+            "2019.01.06-test",
+            "2019.01.06.1-test.2",
+            "7!2019.01.06.1-test.2",
+        ] {
+            let version = Version::parse(input).unwrap();
+            let encoded = version.encode();
+
+            assert_eq!(version.encoded_len(), encoded.len());
+            assert_eq!((version, &[][..]), Version::decode(&encoded).unwrap());
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let encoded = Version::parse("2019.01.06.1-test.2").unwrap().encode();
+        for len in 0..encoded.len() {
+            assert_eq!(
+                ChronVerError::InvalidBinary,
+                Version::decode(&encoded[..len]).unwrap_err()
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_contents() {
+        let mut encoded = Version::parse("2019.01.06").unwrap().encode();
+        encoded[10] = 3;
+        assert_eq!(
+            ChronVerError::InvalidBinary,
+            Version::decode(&encoded).unwrap_err()
+        );
+
+        encoded[4] = 13;
+        assert!(matches!(
+            Version::decode(&encoded).unwrap_err(),
+            ChronVerError::InvalidComponents(_)
+        ));
+    }
+}
@@ -0,0 +1,108 @@
+//! Parsing of versions straight from raw bytes.
+
+use std::convert::TryFrom;
+
+use crate::{ChronVerError, ParseOptions, Version};
+
+impl Version {
+    /// Parse raw bytes into a version, for example when reading it from a binary protocol.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, Version};
+    ///
+    /// assert_eq!(
+    ///     Version::parse_bytes(b"2020.01.06.1-beta"),
+    ///     Version::parse("2020.01.06.1-beta")
+    /// );
+    /// assert!(matches!(
+    ///     Version::parse_bytes(b"2020.01.06-\xff"),
+    ///     Err(ChronVerError::InvalidUtf8(_))
+    /// ));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the bytes are not valid UTF-8. Otherwise, the same errors as for
+    /// [`Version::parse`] can occur.
+    pub fn parse_bytes(version: &[u8]) -> Result<Self, ChronVerError> {
+        Self::parse_bytes_with(version, &ParseOptions::default())
+    }
+
+    /// Parse raw bytes into a version, respecting the given options like
+    /// [`Version::parse_with`]. This is the byte counterpart, that [`Version::parse_bytes`] and
+    /// [`Parser::parse_bytes`](crate::Parser::parse_bytes) are built on.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ParseOptions, Version};
+    ///
+    /// let options = ParseOptions::new().strict(true);
+    ///
+    /// assert!(Version::parse_bytes_with(b"2020.01.06-beta", &options).is_ok());
+    /// assert_eq!(
+    ///     Version::parse_bytes_with(b"2020.01.06-be ta", &options),
+    ///     Err(ChronVerError::InvalidLabelChar(' '))
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the bytes are not valid UTF-8. Otherwise, the same errors as for
+    /// [`Version::parse_with`] can occur.
+    pub fn parse_bytes_with(version: &[u8], options: &ParseOptions) -> Result<Self, ChronVerError> {
+        Self::parse_with(std::str::from_utf8(version)?, options)
+    }
+}
+
+impl TryFrom<&[u8]> for Version {
+    type Error = ChronVerError;
+
+    #[inline]
+    fn try_from(bytes: &[u8]) -> Result<Self, Self::Error> {
+        Self::parse_bytes(bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_as_str() {
+        for input in [
+            "2019.01.06",
+            "2019.01.06.12",
+            "1!2019.01.06.2-a.1",
+            "2019.01.06-a!b-c",
+            "2019.01.06-ü",
+            "2019.01.06-",
+            "2019",
+            "2019-01-06",
+            "2019.01.0ü",
+            "2019.01.06.1!x",
+            "2019.01.06.x",
+            "2019.01.06+a",
+            "99999999999!2019.01.06",
+            "",
+        ] {
+            assert_eq!(
+                Version::parse(input),
+                Version::parse_bytes(input.as_bytes()),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_utf8() {
+        for input in [&b"2019.01.06-\xff"[..], b"2019.01.06\xff", b"\xff"] {
+            assert!(matches!(
+                Version::try_from(input),
+                Err(ChronVerError::InvalidUtf8(_))
+            ));
+        }
+    }
+}
@@ -0,0 +1,222 @@
+//! Helpers to stamp versions with metadata from common CI environments.
+
+use std::env;
+
+use time::{Date, OffsetDateTime};
+
+use crate::{Label, Version};
+
+/// Environment variables that may hold the commit hash, in order of precedence.
+const COMMIT_VARS: &[&str] = &[
+    "GITHUB_SHA",
+    "CI_COMMIT_SHA",
+    "CIRCLE_SHA1",
+    "BUILD_SOURCEVERSION",
+    "GIT_COMMIT",
+];
+/// Environment variables that may hold the build number, in order of precedence.
+const BUILD_VARS: &[&str] = &[
+    "GITHUB_RUN_NUMBER",
+    "CI_PIPELINE_IID",
+    "CIRCLE_BUILD_NUM",
+    "BUILD_BUILDID",
+    "BUILD_NUMBER",
+];
+/// Environment variables that may hold the branch name, in order of precedence.
+const BRANCH_VARS: &[&str] = &[
+    "GITHUB_HEAD_REF",
+    "GITHUB_REF_NAME",
+    "CI_COMMIT_REF_NAME",
+    "CIRCLE_BRANCH",
+    "BUILD_SOURCEBRANCHNAME",
+    "BRANCH_NAME",
+];
+/// Branches that are considered the main line and produce versions without a label.
+const MAIN_BRANCHES: &[&str] = &["main", "master"];
+
+/// Build metadata, as provided by the CI environment.
+///
+/// Recognized are the variables of GitHub Actions, GitLab CI, Circle CI, Azure Pipelines and
+/// Jenkins. Unset or empty variables are skipped, as are build numbers that aren't numeric.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct CiMetadata {
+    /// Hash of the commit that is being built.
+    pub commit: Option<String>,
+    /// Sequential number of the build.
+    pub build: Option<u32>,
+    /// Name of the branch that is being built.
+    pub branch: Option<String>,
+}
+
+impl CiMetadata {
+    /// Read the metadata from the current process' environment.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::CiMetadata;
+    ///
+    /// let metadata = CiMetadata::from_env();
+    /// println!("building commit {:?}", metadata.commit);
+    /// ```
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_vars(|key| env::var(key).ok())
+    }
+
+    /// Read the metadata through the given lookup function, which receives variable names and
+    /// returns their values if set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::CiMetadata;
+    ///
+    /// let metadata = CiMetadata::from_vars(|key| match key {
+    ///     "GITHUB_RUN_NUMBER" => Some("42".to_owned()),
+    ///     "GITHUB_REF_NAME" => Some("main".to_owned()),
+    ///     _ => None,
+    /// });
+    ///
+    /// assert_eq!(metadata.build, Some(42));
+    /// assert_eq!(metadata.branch.as_deref(), Some("main"));
+    /// ```
+    #[must_use]
+    pub fn from_vars(lookup: impl Fn(&str) -> Option<String>) -> Self {
+        let values = |vars: &'static [&'static str]| {
+            vars.iter()
+                .filter_map(|key| lookup(key))
+                .filter(|value| !value.is_empty())
+        };
+        let first = |vars| values(vars).next();
+
+        Self {
+            commit: first(COMMIT_VARS),
+            // A provider may use non-numeric build IDs, so fall back to the next one.
+            build: values(BUILD_VARS).find_map(|value| value.parse().ok()),
+            branch: first(BRANCH_VARS),
+        }
+    }
+
+    /// Create a version for the given date, using the build number as changeset and the branch
+    /// as label. Builds on the main line (`main` or `master`) don't get a label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::CiMetadata;
+    /// use time::macros::date;
+    ///
+    /// let metadata = CiMetadata {
+    ///     commit: None,
+    ///     build: Some(7),
+    ///     branch: Some("feature".to_owned()),
+    /// };
+    ///
+    /// assert_eq!(
+    ///     metadata.to_version(date!(2020-01-06)).to_string(),
+    ///     "2020.01.06.7-feature"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_version(&self, date: Date) -> Version {
+        Version {
+            epoch: 0,
+            date,
+            changeset: self.build.unwrap_or_default(),
+            label: self
+                .branch
+                .as_deref()
+                .filter(|branch| !MAIN_BRANCHES.contains(branch))
+                .map(Label::parse),
+        }
+    }
+}
+
+impl Version {
+    /// Create a version for the current date, filled with metadata from the CI environment (see
+    /// [`CiMetadata`] for details).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::with_metadata_from_env();
+    /// println!("stamping build as {version}");
+    /// ```
+    #[must_use]
+    pub fn with_metadata_from_env() -> Self {
+        CiMetadata::from_env().to_version(OffsetDateTime::now_utc().date())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn precedence() {
+        let metadata = CiMetadata::from_vars(|key| match key {
+            "GITHUB_SHA" => Some(String::new()),
+            "CI_COMMIT_SHA" => Some("abc".to_owned()),
+            "BUILD_NUMBER" => Some("3".to_owned()),
+            "GITHUB_RUN_NUMBER" => Some("5".to_owned()),
+            _ => None,
+        });
+
+        assert_eq!(
+            CiMetadata {
+                commit: Some("abc".to_owned()),
+                build: Some(5),
+                branch: None,
+            },
+            metadata
+        );
+    }
+
+    #[test]
+    fn non_numeric_build() {
+        let metadata = CiMetadata::from_vars(|key| match key {
+            "GITHUB_RUN_NUMBER" => Some("run-5".to_owned()),
+            "BUILD_BUILDID" => Some("8".to_owned()),
+            "BUILD_NUMBER" => Some("3".to_owned()),
+            _ => None,
+        });
+
+        assert_eq!(Some(8), metadata.build);
+    }
+
+    #[test]
+    fn main_branch() {
+        let metadata = CiMetadata {
+            commit: None,
+            build: Some(2),
+            branch: Some("master".to_owned()),
+        };
+
+        assert_eq!(
+            "2020.01.06.2",
+            metadata.to_version(date!(2020 - 01 - 06)).to_string()
+        );
+    }
+
+    #[test]
+    fn feature_branch() {
+        let metadata = CiMetadata {
+            commit: None,
+            build: None,
+            branch: Some("release.4".to_owned()),
+        };
+
+        assert_eq!(
+            Some(Label::Feature {
+                branch: "release".to_owned(),
+                changeset: 4
+            }),
+            metadata.to_version(date!(2020 - 01 - 06)).label
+        );
+    }
+}
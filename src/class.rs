@@ -0,0 +1,107 @@
+//! Coarse classification of releases by their label.
+
+use crate::{Label, Version, BREAK_LABEL};
+
+/// The class of a release, derived from its label, without carrying the label's content.
+///
+/// Classes are ordered as declared, from stable to feature releases.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{ReleaseClass, Version};
+///
+/// let mut versions = ["2020.01.06-login", "2020.01.06.1-break", "2020.01.07"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+/// versions.sort_by_key(Version::class);
+///
+/// let classes = versions.iter().map(Version::class).collect::<Vec<_>>();
+/// assert_eq!(
+///     classes,
+///     [ReleaseClass::Stable, ReleaseClass::Breaking, ReleaseClass::Feature]
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ReleaseClass {
+    /// A regular release without any label.
+    Stable,
+    /// A release with breaking changes, marked by the `break` label.
+    Breaking,
+    /// A release with any other label, usually from a feature branch.
+    Feature,
+}
+
+impl ReleaseClass {
+    /// Get the name of this class in lowercase, for example to use as metric label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::ReleaseClass;
+    ///
+    /// assert_eq!(ReleaseClass::Breaking.as_str(), "breaking");
+    /// ```
+    #[must_use]
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Stable => "stable",
+            Self::Breaking => "breaking",
+            Self::Feature => "feature",
+        }
+    }
+}
+
+impl Label {
+    /// Get the release class that this label represents.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Label, ReleaseClass};
+    ///
+    /// assert_eq!(Label::parse("break").class(), ReleaseClass::Breaking);
+    /// assert_eq!(Label::parse("login.2").class(), ReleaseClass::Feature);
+    /// ```
+    #[must_use]
+    pub fn class(&self) -> ReleaseClass {
+        match self.as_str() {
+            Some(BREAK_LABEL) => ReleaseClass::Breaking,
+            _ => ReleaseClass::Feature,
+        }
+    }
+}
+
+impl Version {
+    /// Get the release class of this version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ReleaseClass, Version};
+    ///
+    /// assert_eq!(Version::parse("2020.01.06").unwrap().class(), ReleaseClass::Stable);
+    /// assert_eq!(Version::parse("2020.01.06-break").unwrap().class(), ReleaseClass::Breaking);
+    /// ```
+    #[must_use]
+    pub fn class(&self) -> ReleaseClass {
+        self.label
+            .as_ref()
+            .map_or(ReleaseClass::Stable, Label::class)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classes() {
+        let class = |v: &str| Version::parse(v).unwrap().class();
+        assert_eq!(ReleaseClass::Stable, class("2019.01.06.1"));
+        assert_eq!(ReleaseClass::Breaking, class("2019.01.06.1-break"));
+        assert_eq!(ReleaseClass::Feature, class("2019.01.06.1-break.1"));
+        assert_eq!(ReleaseClass::Feature, class("2019.01.06.1-breaking"));
+    }
+}
@@ -0,0 +1,153 @@
+//! Source of the current date, replaceable for testing.
+
+use time::{Date, OffsetDateTime};
+
+use crate::Version;
+
+/// A source for the current date.
+///
+/// The [`SystemClock`] is used by default, but any fixed [`Date`] or closure returning a date
+/// can be used as well, which is mostly useful for tests.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::Clock;
+/// use time::macros::date;
+///
+/// let clock = date!(2020-01-06);
+/// assert_eq!(clock.today(), date!(2020-01-06));
+///
+/// let clock = || date!(2020-01-07);
+/// assert_eq!(clock.today(), date!(2020-01-07));
+/// ```
+pub trait Clock {
+    /// Get the current date.
+    fn today(&self) -> Date;
+}
+
+/// Clock that reads the current date from the system, in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{Clock, SystemClock};
+///
+/// println!("today is {}", SystemClock.today());
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn today(&self) -> Date {
+        OffsetDateTime::now_utc().date()
+    }
+}
+
+impl Clock for Date {
+    fn today(&self) -> Date {
+        *self
+    }
+}
+
+impl<F> Clock for F
+where
+    F: Fn() -> Date,
+{
+    fn today(&self) -> Date {
+        self()
+    }
+}
+
+impl Version {
+    /// Check whether this version was released today.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// assert!(Version::default().is_today());
+    /// assert!(!Version::parse("2020.01.06").unwrap().is_today());
+    /// ```
+    #[must_use]
+    pub fn is_today(&self) -> bool {
+        self.is_today_with_clock(&SystemClock)
+    }
+
+    /// Check whether this version was released today, according to the given clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    /// use time::macros::date;
+    ///
+    /// let version = Version::parse("2020.01.06.1").unwrap();
+    ///
+    /// assert!(version.is_today_with_clock(&date!(2020-01-06)));
+    /// assert!(!version.is_today_with_clock(&date!(2020-01-07)));
+    /// ```
+    #[must_use]
+    pub fn is_today_with_clock(&self, clock: &impl Clock) -> bool {
+        self.date == clock.today()
+    }
+
+    /// Check whether this version was released in the current month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// assert!(Version::default().is_from_current_month());
+    /// assert!(!Version::parse("2020.01.06").unwrap().is_from_current_month());
+    /// ```
+    #[must_use]
+    pub fn is_from_current_month(&self) -> bool {
+        self.is_from_current_month_with_clock(&SystemClock)
+    }
+
+    /// Check whether this version was released in the current month, according to the given
+    /// clock.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    /// use time::macros::date;
+    ///
+    /// let version = Version::parse("2020.01.06.1").unwrap();
+    ///
+    /// assert!(version.is_from_current_month_with_clock(&date!(2020-01-31)));
+    /// assert!(!version.is_from_current_month_with_clock(&date!(2021-01-06)));
+    /// ```
+    #[must_use]
+    pub fn is_from_current_month_with_clock(&self, clock: &impl Clock) -> bool {
+        let today = clock.today();
+        self.date.year() == today.year() && self.date.month() == today.month()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn today() {
+        let version = Version::parse("2019.01.06.3-test").unwrap();
+        assert!(version.is_today_with_clock(&date!(2019 - 01 - 06)));
+        assert!(version.is_today_with_clock(&|| date!(2019 - 01 - 06)));
+        assert!(!version.is_today_with_clock(&date!(2020 - 01 - 06)));
+    }
+
+    #[test]
+    fn current_month() {
+        let version = Version::parse("2019.01.06").unwrap();
+        assert!(version.is_from_current_month_with_clock(&date!(2019 - 01 - 01)));
+        assert!(!version.is_from_current_month_with_clock(&date!(2019 - 02 - 06)));
+        assert!(!version.is_from_current_month_with_clock(&date!(2020 - 01 - 06)));
+    }
+}
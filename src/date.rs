@@ -0,0 +1,254 @@
+//! Calendar helpers for the date part of versions.
+
+use time::{util, Date, Weekday};
+
+use crate::Version;
+
+/// Which days of the week are considered the weekend, for
+/// [`next_business_day`](DateExt::next_business_day).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum WeekendPolicy {
+    /// Saturday and Sunday are the weekend.
+    SaturdaySunday,
+    /// Friday and Saturday are the weekend.
+    FridaySaturday,
+    /// Every day of the week is a business day.
+    NoWeekend,
+}
+
+impl WeekendPolicy {
+    /// Check whether the given day of the week is part of the weekend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::WeekendPolicy;
+    /// use time::Weekday;
+    ///
+    /// assert!(WeekendPolicy::SaturdaySunday.is_weekend(Weekday::Sunday));
+    /// assert!(!WeekendPolicy::FridaySaturday.is_weekend(Weekday::Sunday));
+    /// ```
+    #[must_use]
+    pub const fn is_weekend(self, weekday: Weekday) -> bool {
+        match self {
+            Self::SaturdaySunday => matches!(weekday, Weekday::Saturday | Weekday::Sunday),
+            Self::FridaySaturday => matches!(weekday, Weekday::Friday | Weekday::Saturday),
+            Self::NoWeekend => false,
+        }
+    }
+}
+
+/// Extension methods for [`Date`], dealing with the edges of the calendar.
+pub trait DateExt: private::Sealed {
+    /// Check whether the year of this date is a leap year.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::DateExt;
+    /// use time::macros::date;
+    ///
+    /// assert!(date!(2020-01-06).is_leap_year());
+    /// assert!(!date!(2100-01-06).is_leap_year());
+    /// ```
+    #[must_use]
+    fn is_leap_year(&self) -> bool;
+
+    /// Get the last day of the month that this date falls into.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::DateExt;
+    /// use time::macros::date;
+    ///
+    /// assert_eq!(date!(2020-02-06).last_day_of_month(), date!(2020-02-29));
+    /// assert_eq!(date!(2021-02-06).last_day_of_month(), date!(2021-02-28));
+    /// ```
+    #[must_use]
+    fn last_day_of_month(&self) -> Self;
+
+    /// Get the first business day starting from this date, skipping weekends and the given
+    /// holidays. If this date is a business day already, it is returned as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{DateExt, WeekendPolicy};
+    /// use time::macros::date;
+    ///
+    /// let holidays = [date!(2020-12-28)];
+    ///
+    /// // Saturday, Sunday and the holiday on Monday are skipped.
+    /// assert_eq!(
+    ///     date!(2020-12-26).next_business_day(WeekendPolicy::SaturdaySunday, &holidays),
+    ///     date!(2020-12-29)
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no business day left until the largest representable date.
+    #[must_use]
+    fn next_business_day(&self, weekend: WeekendPolicy, holidays: &[Date]) -> Self;
+}
+
+impl DateExt for Date {
+    fn is_leap_year(&self) -> bool {
+        util::is_leap_year(self.year())
+    }
+
+    // The replacement `days_in_month` needs a newer `time` than the minimum supported one.
+    #[allow(deprecated)]
+    fn last_day_of_month(&self) -> Self {
+        self.replace_day(util::days_in_year_month(self.year(), self.month()))
+            .expect("last day of month is always valid")
+    }
+
+    fn next_business_day(&self, weekend: WeekendPolicy, holidays: &[Date]) -> Self {
+        let mut date = *self;
+        while weekend.is_weekend(date.weekday()) || holidays.contains(&date) {
+            date = date
+                .next_day()
+                .expect("no business day before the end of time");
+        }
+        date
+    }
+}
+
+impl Version {
+    /// Get the first version of the last day in the month of this version.
+    ///
+    /// This is the lowest version of that day, so as inclusive upper bound it leaves out later
+    /// releases of the same day, like `2020.02.29.5` or `2020.02.29-beta`. To select all
+    /// releases until the end of the month, use the first version of the following day as
+    /// exclusive bound instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.02.06.3-test").unwrap();
+    /// let end = version.end_of_month();
+    /// assert_eq!(end.to_string(), "2020.02.29");
+    ///
+    /// let late = Version::parse("2020.02.29.5").unwrap();
+    /// let next_day = Version::from(end.date.next_day().unwrap());
+    /// assert!(!(version.clone()..=end).contains(&late));
+    /// assert!((version..next_day).contains(&late));
+    /// ```
+    #[must_use]
+    pub fn end_of_month(&self) -> Self {
+        Self {
+            epoch: self.epoch,
+            ..Self::from(self.date.last_day_of_month())
+        }
+    }
+
+    /// Move the date of this version forward to the next business day (see
+    /// [`DateExt::next_business_day`]). If the date changes, the changeset is reset, as it
+    /// counts releases of the original day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, WeekendPolicy};
+    ///
+    /// let friday = Version::parse("2020.01.10.2").unwrap();
+    /// let saturday = Version::parse("2020.01.11.2-break").unwrap();
+    ///
+    /// assert_eq!(
+    ///     friday.clone().next_business_day(WeekendPolicy::SaturdaySunday, &[]),
+    ///     friday
+    /// );
+    /// assert_eq!(
+    ///     saturday
+    ///         .next_business_day(WeekendPolicy::SaturdaySunday, &[])
+    ///         .to_string(),
+    ///     "2020.01.13-break"
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if there is no business day left until the largest representable date.
+    #[must_use]
+    pub fn next_business_day(self, weekend: WeekendPolicy, holidays: &[Date]) -> Self {
+        let date = self.date.next_business_day(weekend, holidays);
+        if date == self.date {
+            self
+        } else {
+            Self {
+                date,
+                changeset: 0,
+                ..self
+            }
+        }
+    }
+}
+
+/// Prevent implementations of [`DateExt`] outside of this crate.
+mod private {
+    /// Marker for types that may implement the extension traits.
+    pub trait Sealed {}
+
+    impl Sealed for time::Date {}
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn leap_years() {
+        assert!(date!(2000 - 06 - 01).is_leap_year());
+        assert!(date!(2024 - 06 - 01).is_leap_year());
+        assert!(!date!(1900 - 06 - 01).is_leap_year());
+        assert!(!date!(2023 - 06 - 01).is_leap_year());
+    }
+
+    #[test]
+    fn next_business_day() {
+        let holidays = [date!(2020 - 01 - 06)];
+
+        assert_eq!(
+            date!(2020 - 01 - 05),
+            date!(2020 - 01 - 05).next_business_day(WeekendPolicy::NoWeekend, &holidays)
+        );
+        assert_eq!(
+            date!(2020 - 01 - 07),
+            date!(2020 - 01 - 04).next_business_day(WeekendPolicy::SaturdaySunday, &holidays)
+        );
+        assert_eq!(
+            date!(2020 - 01 - 05),
+            date!(2020 - 01 - 03).next_business_day(WeekendPolicy::FridaySaturday, &holidays)
+        );
+    }
+
+    #[test]
+    fn last_day_of_month() {
+        assert_eq!(
+            date!(2020 - 01 - 31),
+            date!(2020 - 01 - 01).last_day_of_month()
+        );
+        assert_eq!(
+            date!(2020 - 04 - 30),
+            date!(2020 - 04 - 30).last_day_of_month()
+        );
+        assert_eq!(
+            date!(2000 - 02 - 29),
+            date!(2000 - 02 - 01).last_day_of_month()
+        );
+        assert_eq!(
+            date!(1900 - 02 - 28),
+            date!(1900 - 02 - 01).last_day_of_month()
+        );
+        assert_eq!(
+            date!(2020 - 12 - 31),
+            date!(2020 - 12 - 24).last_day_of_month()
+        );
+    }
+}
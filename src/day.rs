@@ -0,0 +1,161 @@
+//! Releases of a single day, without distinguishing their changesets.
+
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use time::Date;
+
+use crate::{split_epoch, ChronVerError, Label, Version, DATE_LENGTH};
+
+/// A logical release, made up of only the epoch, date and label of a version. All changesets of the
+/// same day and label collapse into the same release day, for example in roll-up reports.
+///
+/// The text form is a version without changeset, like `2020.01.06`, `2020.01.06-break` or
+/// `1!2020.01.06`.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use chronver::{ReleaseDay, Version};
+///
+/// let days = ["2020.01.06", "2020.01.06.1", "2020.01.06.2-break"]
+///     .iter()
+///     .map(|v| ReleaseDay::from(Version::parse(v).unwrap()))
+///     .collect::<BTreeSet<_>>();
+///
+/// let days = days.iter().map(ToString::to_string).collect::<Vec<_>>();
+/// assert_eq!(days, ["2020.01.06", "2020.01.06-break"]);
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct ReleaseDay {
+    /// The epoch of the release (see [`Version::epoch`]).
+    pub epoch: u32,
+    /// The date of release.
+    pub date: Date,
+    /// The optional label.
+    pub label: Option<Label>,
+}
+
+impl ReleaseDay {
+    /// Parse a string into a release day.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ReleaseDay};
+    ///
+    /// let day = ReleaseDay::parse("2020.01.06-break").unwrap();
+    /// assert_eq!(day.to_string(), "2020.01.06-break");
+    ///
+    /// assert_eq!(
+    ///     ReleaseDay::parse("2020.01.06.1"),
+    ///     Err(ChronVerError::UnexpectedChangeset)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur. In addition, an error is returned
+    /// if the string contains a changeset.
+    pub fn parse(day: &str) -> Result<Self, ChronVerError> {
+        let version = Version::parse(day)?;
+        let (_, date) = split_epoch(day)?;
+        if date.as_bytes().get(DATE_LENGTH) == Some(&b'.') {
+            return Err(ChronVerError::UnexpectedChangeset);
+        }
+
+        Ok(version.into())
+    }
+
+    /// Create the first version of this release day, without changeset.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::ReleaseDay;
+    ///
+    /// let day = ReleaseDay::parse("2020.01.06-beta").unwrap();
+    /// assert_eq!(day.to_version().to_string(), "2020.01.06-beta");
+    /// ```
+    #[must_use]
+    pub fn to_version(&self) -> Version {
+        Version {
+            epoch: self.epoch,
+            date: self.date,
+            changeset: 0,
+            label: self.label.clone(),
+        }
+    }
+}
+
+impl From<Version> for ReleaseDay {
+    fn from(version: Version) -> Self {
+        Self {
+            epoch: version.epoch,
+            date: version.date,
+            label: version.label,
+        }
+    }
+}
+
+impl Display for ReleaseDay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.to_version().fmt(f)
+    }
+}
+
+impl FromStr for ReleaseDay {
+    type Err = ChronVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for ReleaseDay {
+    type Error = ChronVerError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        let day = ReleaseDay::parse("2019.01.06-a.1").unwrap();
+        assert_eq!(Some(Label::parse("a.1")), day.label);
+        assert_eq!("2019.01.06-a.1", day.to_string());
+
+        assert_eq!(
+            Err(ChronVerError::UnexpectedChangeset),
+            ReleaseDay::parse("2019.01.06.0")
+        );
+        assert_eq!(Err(ChronVerError::TooShort), ReleaseDay::parse("2019"));
+
+        let day = ReleaseDay::parse("1!2019.01.06").unwrap();
+        assert_eq!(1, day.epoch);
+        assert_eq!("1!2019.01.06", day.to_string());
+        assert_eq!(
+            Err(ChronVerError::UnexpectedChangeset),
+            ReleaseDay::parse("1!2019.01.06.1")
+        );
+    }
+
+    #[test]
+    fn ordering() {
+        let day = |d| ReleaseDay::parse(d).unwrap();
+
+        assert!(day("2019.01.06") < day("2019.01.06-a"));
+        assert!(day("2019.01.06-z") < day("2019.01.07"));
+        assert!(day("2019.01.07") < day("1!2019.01.06"));
+    }
+}
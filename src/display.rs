@@ -0,0 +1,244 @@
+//! Configurable rendering of versions.
+
+use std::fmt::{self, Display};
+
+use crate::{Version, DATE_FORMAT};
+
+/// Display adapter for a [`Version`] that allows to tweak how the changeset is rendered,
+/// created by [`Version::padded`] or [`Version::with_changeset_offset`].
+///
+/// # Examples
+///
+/// ```
+/// use chronver::Version;
+///
+/// let version = Version::parse("2024.04.03.2").unwrap();
+/// assert_eq!(
+///     version.padded(3).changeset_offset(40).to_string(),
+///     "2024.04.03.042"
+/// );
+/// ```
+#[derive(Debug, Clone, Copy)]
+#[must_use]
+pub struct VersionDisplay<'a> {
+    /// The version to render.
+    version: &'a Version,
+    /// Minimum width of the changeset, if it should be zero-padded.
+    width: Option<usize>,
+    /// Amount that is added to the changeset before rendering.
+    offset: u32,
+}
+
+impl<'a> VersionDisplay<'a> {
+    /// Create an adapter that renders the version in its canonical form.
+    pub(crate) const fn new(version: &'a Version) -> Self {
+        Self {
+            version,
+            width: None,
+            offset: 0,
+        }
+    }
+
+    /// Zero-pad the changeset to the given width. The changeset is always included in this form,
+    /// even if it is 0, so that all versions have the same length and sort correctly as plain
+    /// strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2024.04.03").unwrap();
+    /// assert_eq!(
+    ///     version.with_changeset_offset(0).padded(2).to_string(),
+    ///     "2024.04.03.00"
+    /// );
+    /// ```
+    pub const fn padded(self, width: usize) -> Self {
+        Self {
+            width: Some(width),
+            ..self
+        }
+    }
+
+    /// Add the given offset to the changeset, for projects that continue the counter of a
+    /// previous versioning scheme. Use [`ParseOptions::changeset_offset`] to remove it again
+    /// when parsing.
+    ///
+    /// Rendering fails with [`fmt::Error`] if the changeset and the offset together exceed
+    /// [`u32::MAX`], as the result would not be a valid version.
+    ///
+    /// [`ParseOptions::changeset_offset`]: crate::ParseOptions::changeset_offset
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2024.04.03").unwrap();
+    /// assert_eq!(version.padded(1).changeset_offset(47).to_string(), "2024.04.03.47");
+    /// ```
+    pub const fn changeset_offset(self, offset: u32) -> Self {
+        Self { offset, ..self }
+    }
+}
+
+impl Display for VersionDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let version = self.version;
+        // A changeset beyond `u32` couldn't be parsed again, so it is not rendered at all.
+        let changeset = version
+            .changeset
+            .checked_add(self.offset)
+            .ok_or(fmt::Error)?;
+
+        if version.epoch > 0 {
+            write!(f, "{}!", version.epoch)?;
+        }
+        f.write_str(&version.date.format(&DATE_FORMAT).map_err(|_| fmt::Error)?)?;
+        match self.width {
+            Some(width) => write!(f, ".{changeset:0width$}")?,
+            None if changeset > 0 => write!(f, ".{changeset}")?,
+            None => {}
+        }
+        if let Some(label) = &version.label {
+            write!(f, "-{label}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Debug adapter for a [`Version`] that includes the canonical string form next to the fields,
+/// created by [`Version::debug_canonical`].
+///
+/// # Examples
+///
+/// ```
+/// use chronver::Version;
+///
+/// let version = Version::parse("2020.01.06.1").unwrap();
+/// assert_eq!(
+///     format!("{:?}", version.debug_canonical()),
+///     r#"Version("2020.01.06.1") { epoch: 0, date: 2020-01-06, changeset: 1, label: None }"#
+/// );
+/// ```
+#[derive(Clone, Copy)]
+#[must_use]
+pub struct CanonicalDebug<'a>(&'a Version);
+
+impl fmt::Debug for CanonicalDebug<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let Version {
+            epoch,
+            date,
+            changeset,
+            label,
+        } = self.0;
+
+        f.debug_struct(&format!("Version({:?})", self.0.to_string()))
+            .field("epoch", epoch)
+            .field("date", date)
+            .field("changeset", changeset)
+            .field("label", label)
+            .finish()
+    }
+}
+
+impl Version {
+    /// Format the version for debugging, with the canonical string form in front of the fields,
+    /// so it can be copied from logs straight into [`Version::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06-test").unwrap();
+    /// let debug = format!("{:?}", version.debug_canonical());
+    ///
+    /// assert!(debug.starts_with(r#"Version("2020.01.06-test") {"#));
+    /// ```
+    pub const fn debug_canonical(&self) -> CanonicalDebug<'_> {
+        CanonicalDebug(self)
+    }
+
+    /// Render the version with the changeset zero-padded to the given width. The changeset is
+    /// always included in this form, even if it is 0, so that all versions have the same
+    /// length and sort correctly as plain strings.
+    ///
+    /// Padded changesets are accepted by [`Version::parse`] as well.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2024.04.03.2-test").unwrap();
+    /// assert_eq!(version.padded(3).to_string(), "2024.04.03.002-test");
+    ///
+    /// let version = Version::parse("2024.04.03").unwrap();
+    /// assert_eq!(version.padded(3).to_string(), "2024.04.03.000");
+    ///
+    /// assert_eq!(Version::parse("2024.04.03.002").unwrap().changeset, 2);
+    /// ```
+    pub const fn padded(&self, width: usize) -> VersionDisplay<'_> {
+        VersionDisplay::new(self).padded(width)
+    }
+
+    /// Render the version with the given offset added to the changeset, for projects that
+    /// continue the counter of a previous versioning scheme. A changeset of 0 is still omitted,
+    /// unless the offset makes it non-zero. Rendering fails if the sum exceeds [`u32::MAX`] (see
+    /// [`VersionDisplay::changeset_offset`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2024.04.03.1").unwrap();
+    /// assert_eq!(version.with_changeset_offset(47).to_string(), "2024.04.03.48");
+    /// ```
+    pub const fn with_changeset_offset(&self, offset: u32) -> VersionDisplay<'_> {
+        VersionDisplay::new(self).changeset_offset(offset)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::*;
+
+    #[test]
+    fn debug_canonical_pretty() {
+        let version = Version::parse("2019.01.06-a").unwrap();
+
+        assert_eq!(
+            "Version(\"2019.01.06-a\") {\n    \
+             epoch: 0,\n    \
+             date: 2019-01-06,\n    \
+             changeset: 0,\n    \
+             label: Some(\n        \
+             Text(\n            \
+             \"a\",\n        \
+             ),\n    \
+             ),\n\
+             }",
+            format!("{:#?}", version.debug_canonical())
+        );
+    }
+
+    #[test]
+    fn offset_beyond_changeset_range() {
+        let version = Version::parse("2019.01.06.2-test").unwrap();
+        let mut rendered = String::new();
+
+        assert!(write!(rendered, "{}", version.with_changeset_offset(u32::MAX - 2)).is_ok());
+        assert_eq!("2019.01.06.4294967295-test", rendered);
+        assert!(Version::parse(&rendered).is_ok());
+        assert_eq!(
+            Err(fmt::Error),
+            write!(rendered, "{}", version.with_changeset_offset(u32::MAX - 1))
+        );
+    }
+}
@@ -0,0 +1,172 @@
+//! Simple glob patterns to select labels by their feature branch name.
+
+use std::fmt::{self, Display};
+
+use crate::Label;
+
+/// A glob pattern that matches against the branch name of a [`Label`].
+///
+/// The pattern syntax is intentionally small:
+///
+/// - `*` matches any sequence of characters, including an empty one.
+/// - `?` matches exactly one character.
+/// - Any other character matches itself.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{FeatureGlob, Label};
+///
+/// let glob = FeatureGlob::new("release/*");
+///
+/// assert!(glob.matches("release/2020"));
+/// assert!(glob.matches_label(&Label::parse("release/2020.3")));
+/// assert!(!glob.matches_label(&Label::parse("hotfix/2020.3")));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FeatureGlob {
+    /// The raw pattern, as given by the user.
+    pattern: String,
+}
+
+impl FeatureGlob {
+    /// Create a new glob from the given pattern.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::FeatureGlob;
+    ///
+    /// assert_eq!(FeatureGlob::new("release/*").as_str(), "release/*");
+    /// ```
+    #[must_use]
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            pattern: pattern.to_owned(),
+        }
+    }
+
+    /// Get the raw pattern of this glob.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::FeatureGlob;
+    ///
+    /// assert_eq!(FeatureGlob::new("feat-?").as_str(), "feat-?");
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Check whether the given name matches this glob.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::FeatureGlob;
+    ///
+    /// let glob = FeatureGlob::new("feat-?");
+    ///
+    /// assert!(glob.matches("feat-a"));
+    /// assert!(!glob.matches("feat-ab"));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, name: &str) -> bool {
+        let pattern = self.pattern.chars().collect::<Vec<_>>();
+        let name = name.chars().collect::<Vec<_>>();
+
+        let (mut p, mut n) = (0, 0);
+        let mut backtrack = None;
+
+        while n < name.len() {
+            match pattern.get(p) {
+                Some('*') => {
+                    backtrack = Some((p, n));
+                    p += 1;
+                }
+                Some(&c) if c == '?' || c == name[n] => {
+                    p += 1;
+                    n += 1;
+                }
+                _ => match backtrack {
+                    Some((bp, bn)) => {
+                        backtrack = Some((bp, bn + 1));
+                        p = bp + 1;
+                        n = bn + 1;
+                    }
+                    None => return false,
+                },
+            }
+        }
+
+        pattern[p..].iter().all(|&c| c == '*')
+    }
+
+    /// Check whether the given label matches this glob. For [`Label::Feature`] only the branch
+    /// name is matched, while a [`Label::Text`] is matched as a whole.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{FeatureGlob, Label};
+    ///
+    /// let glob = FeatureGlob::new("release/*");
+    ///
+    /// assert!(glob.matches_label(&Label::parse("release/a.1")));
+    /// assert!(glob.matches_label(&Label::parse("release/a")));
+    /// ```
+    #[must_use]
+    pub fn matches_label(&self, label: &Label) -> bool {
+        match label {
+            Label::Text(text) => self.matches(text),
+            Label::Feature { branch, .. } => self.matches(branch),
+        }
+    }
+}
+
+impl Display for FeatureGlob {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.pattern)
+    }
+}
+
+impl From<&str> for FeatureGlob {
+    #[inline]
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn literal() {
+        let glob = FeatureGlob::new("release");
+        assert!(glob.matches("release"));
+        assert!(!glob.matches("releases"));
+        assert!(!glob.matches("releas"));
+    }
+
+    #[test]
+    fn wildcards() {
+        let glob = FeatureGlob::new("*/v?-*");
+        assert!(glob.matches("team/v1-fix"));
+        assert!(glob.matches("/v2-"));
+        assert!(!glob.matches("team/v10-fix"));
+        assert!(FeatureGlob::new("*").matches(""));
+        assert!(FeatureGlob::new("a*b*c").matches("aXbYbZc"));
+        assert!(!FeatureGlob::new("a*b*c").matches("aXbYbZ"));
+    }
+
+    #[test]
+    fn labels() {
+        let glob = FeatureGlob::new("release/*");
+        assert!(glob.matches_label(&Label::parse("release/x.3")));
+        assert!(!glob.matches_label(&Label::parse("hotfix/x.3")));
+        assert!(Label::parse("release/x.3").matches_glob("release/*"));
+    }
+}
@@ -0,0 +1,131 @@
+//! Export of releases as iCalendar feed (RFC 5545), so the release history can be subscribed to
+//! in calendar applications.
+
+use std::borrow::Borrow;
+
+use time::Date;
+
+use crate::Version;
+
+/// Maximum length of a content line in bytes, before it is folded onto the next line.
+const MAX_LINE_LENGTH: usize = 75;
+
+/// Render the given versions into an iCalendar feed, with one all-day event for each release.
+/// Breaking releases are flagged with the `BREAKING` category.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{ics, Version};
+///
+/// let versions = [Version::parse("2020.01.07-break").unwrap()];
+///
+/// assert_eq!(
+///     ics::render(&versions),
+///     "BEGIN:VCALENDAR\r\n\
+///      VERSION:2.0\r\n\
+///      PRODID:-//chronver//chronver//EN\r\n\
+///      BEGIN:VEVENT\r\n\
+///      UID:2020.01.07-break@chronver\r\n\
+///      DTSTAMP:20200107T000000Z\r\n\
+///      DTSTART;VALUE=DATE:20200107\r\n\
+///      SUMMARY:Release 2020.01.07-break (breaking)\r\n\
+///      CATEGORIES:BREAKING\r\n\
+///      END:VEVENT\r\n\
+///      END:VCALENDAR\r\n"
+/// );
+/// ```
+pub fn render<I>(versions: I) -> String
+where
+    I: IntoIterator,
+    I::Item: Borrow<Version>,
+{
+    let mut out = String::new();
+    push_line(&mut out, "BEGIN:VCALENDAR");
+    push_line(&mut out, "VERSION:2.0");
+    push_line(&mut out, "PRODID:-//chronver//chronver//EN");
+
+    for version in versions {
+        let version = version.borrow();
+        let text = escape(&version.to_string());
+        let date = format_date(version.date);
+
+        push_line(&mut out, "BEGIN:VEVENT");
+        push_line(&mut out, &format!("UID:{text}@chronver"));
+        push_line(&mut out, &format!("DTSTAMP:{date}T000000Z"));
+        push_line(&mut out, &format!("DTSTART;VALUE=DATE:{date}"));
+        if version.is_breaking() {
+            push_line(&mut out, &format!("SUMMARY:Release {text} (breaking)"));
+            push_line(&mut out, "CATEGORIES:BREAKING");
+        } else {
+            push_line(&mut out, &format!("SUMMARY:Release {text}"));
+        }
+        push_line(&mut out, "END:VEVENT");
+    }
+
+    push_line(&mut out, "END:VCALENDAR");
+    out
+}
+
+/// Format a date in the basic `YYYYMMDD` form.
+fn format_date(date: Date) -> String {
+    format!(
+        "{:04}{:02}{:02}",
+        date.year(),
+        u8::from(date.month()),
+        date.day()
+    )
+}
+
+/// Escape the special characters of a text value.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' | ';' | ',' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\n' => escaped.push_str("\\n"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Append a content line, folding it into multiple lines if it is too long. Lines are only split
+/// between characters, to keep multi-byte characters intact.
+fn push_line(out: &mut String, line: &str) {
+    let mut len = 0;
+    for c in line.chars() {
+        if len + c.len_utf8() > MAX_LINE_LENGTH {
+            out.push_str("\r\n ");
+            len = 1;
+        }
+        out.push(c);
+        len += c.len_utf8();
+    }
+    out.push_str("\r\n");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_text() {
+        let version = Version::parse("2019.01.06-a,b;c\\d").unwrap();
+        assert!(render([version]).contains("SUMMARY:Release 2019.01.06-a\\,b\\;c\\\\d\r\n"));
+    }
+
+    #[test]
+    fn folds_long_lines() {
+        let label = "ü".repeat(60);
+        let feed = render([Version::parse(&format!("2019.01.06-{label}")).unwrap()]);
+
+        assert!(feed.split("\r\n").all(|line| line.len() <= MAX_LINE_LENGTH));
+        assert!(feed
+            .replace("\r\n ", "")
+            .contains(&format!("SUMMARY:Release 2019.01.06-{label}\r\n")));
+    }
+}
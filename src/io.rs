@@ -0,0 +1,134 @@
+//! Reading versions from line-based sources, like `versions.txt` manifests or the output of
+//! `git tag`.
+
+use std::io::{self, BufRead, Lines};
+
+use thiserror::Error;
+
+use crate::{ChronVerError, Version};
+
+/// An error while reading a version, together with the line it occurred on.
+#[derive(Error, Debug)]
+#[error("Failed to read version on line {line}")]
+pub struct LineError {
+    /// The line number, starting at 1.
+    pub line: usize,
+    /// What went wrong on this line.
+    #[source]
+    pub kind: LineErrorKind,
+}
+
+/// The cause of a [`LineError`].
+#[derive(Error, Debug)]
+pub enum LineErrorKind {
+    /// Reading from the underlying source failed.
+    #[error("Failed to read line")]
+    Io(#[from] io::Error),
+    /// The line is not a valid version.
+    #[error("Invalid version")]
+    Parse(#[from] ChronVerError),
+}
+
+/// Iterator over the versions of a line-based source, created by [`read_versions`].
+#[derive(Debug)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ReadVersions<R> {
+    /// The remaining lines of the source, or `None` after a read error.
+    lines: Option<Lines<R>>,
+    /// Number of the last line that was read.
+    line: usize,
+}
+
+impl<R: BufRead> Iterator for ReadVersions<R> {
+    type Item = Result<Version, LineError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let content = self.lines.as_mut()?.next()?;
+            self.line += 1;
+
+            let result = match content {
+                Ok(content) if content.trim().is_empty() => continue,
+                Ok(content) => Version::parse(content.trim()).map_err(LineErrorKind::from),
+                Err(e) => {
+                    // Reading again after an error may fail forever, so stop right away.
+                    self.lines = None;
+                    Err(e.into())
+                }
+            };
+
+            return Some(result.map_err(|kind| LineError {
+                line: self.line,
+                kind,
+            }));
+        }
+    }
+}
+
+/// Read one version per line from the given source. Surrounding whitespace is ignored and
+/// blank lines are skipped, while still being counted for the line numbers of errors.
+///
+/// Invalid lines are reported and reading continues with the next line. After an error of the
+/// underlying reader, the iterator stops.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::io::{self, LineErrorKind};
+///
+/// let input = "2020.01.06\n\n  2020.01.07.1-beta\r\ninvalid\n";
+/// let mut versions = io::read_versions(input.as_bytes());
+///
+/// assert_eq!(versions.next().unwrap().unwrap().to_string(), "2020.01.06");
+/// assert_eq!(versions.next().unwrap().unwrap().to_string(), "2020.01.07.1-beta");
+///
+/// let err = versions.next().unwrap().unwrap_err();
+/// assert_eq!(err.line, 4);
+/// assert!(matches!(err.kind, LineErrorKind::Parse(_)));
+///
+/// assert!(versions.next().is_none());
+/// ```
+pub fn read_versions<R: BufRead>(reader: R) -> ReadVersions<R> {
+    ReadVersions {
+        lines: Some(reader.lines()),
+        line: 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    /// Reader that fails on every read.
+    struct Failing;
+
+    impl Read for Failing {
+        fn read(&mut self, _: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::new(io::ErrorKind::Other, "broken"))
+        }
+    }
+
+    #[test]
+    fn line_numbers() {
+        let results = read_versions(&b"\n2019.01.06\nx\n\n2019.01.0\n"[..])
+            .map(|result| result.map_err(|e| e.line))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![Ok(Version::parse("2019.01.06").unwrap()), Err(3), Err(5)],
+            results
+        );
+    }
+
+    #[test]
+    fn stops_on_io_error() {
+        let mut versions = read_versions(io::BufReader::new(Failing));
+
+        let err = versions.next().unwrap().unwrap_err();
+        assert_eq!(1, err.line);
+        assert!(matches!(err.kind, LineErrorKind::Io(_)));
+        assert!(versions.next().is_none());
+    }
+}
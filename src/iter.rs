@@ -0,0 +1,101 @@
+//! Iterator adapters for streams of versions.
+
+use std::{borrow::Borrow, iter::Peekable};
+
+use time::Date;
+
+use crate::Version;
+
+/// Extension methods for iterators over versions.
+pub trait VersionIteratorExt: Iterator + Sized
+where
+    Self::Item: Borrow<Version>,
+{
+    /// Group consecutive versions with the same date into chunks. The iterator should be sorted
+    /// by date for each date to appear only once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionIteratorExt};
+    /// use time::macros::date;
+    ///
+    /// let versions = ["2020.01.06", "2020.01.06.1-break", "2020.01.07"]
+    ///     .iter()
+    ///     .map(|v| Version::parse(v).unwrap())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// let chunks = versions.iter().chunk_by_date().collect::<Vec<_>>();
+    ///
+    /// assert_eq!(chunks.len(), 2);
+    /// assert_eq!(chunks[0].0, date!(2020-01-06));
+    /// assert_eq!(chunks[0].1.len(), 2);
+    /// assert_eq!(chunks[1].0, date!(2020-01-07));
+    /// ```
+    fn chunk_by_date(self) -> ChunkByDate<Self> {
+        ChunkByDate {
+            iter: self.peekable(),
+        }
+    }
+}
+
+impl<I> VersionIteratorExt for I
+where
+    I: Iterator,
+    I::Item: Borrow<Version>,
+{
+}
+
+/// Iterator that groups versions by their date, created by
+/// [`VersionIteratorExt::chunk_by_date`].
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct ChunkByDate<I: Iterator> {
+    /// The underlying iterator.
+    iter: Peekable<I>,
+}
+
+impl<I> Iterator for ChunkByDate<I>
+where
+    I: Iterator,
+    I::Item: Borrow<Version>,
+{
+    type Item = (Date, Vec<I::Item>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let first = self.iter.next()?;
+        let date = first.borrow().date;
+        let mut chunk = vec![first];
+
+        while let Some(next) = self.iter.next_if(|v| v.borrow().date == date) {
+            chunk.push(next);
+        }
+
+        Some((date, chunk))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn chunks() {
+        let chunks = ["2019.01.06", "2019.01.07-a", "2019.01.07.1", "2019.01.06"]
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .chunk_by_date()
+            .map(|(date, versions)| (date, versions.len()))
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec![
+                (date!(2019 - 01 - 06), 1),
+                (date!(2019 - 01 - 07), 2),
+                (date!(2019 - 01 - 06), 1),
+            ],
+            chunks
+        );
+    }
+}
@@ -0,0 +1,95 @@
+//! Conversion from and to JSON, without setting up serde in the calling code.
+
+use serde_json::json;
+
+use crate::Version;
+
+impl Version {
+    /// Render this version as JSON object with separate fields for each component, sorted by
+    /// name. The label is `null` if the version has none, and the epoch is only included if it
+    /// is not 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1-test.2").unwrap();
+    /// assert_eq!(
+    ///     version.to_json(),
+    ///     r#"{"changeset":1,"day":6,"label":"test.2","month":1,"year":2020}"#
+    /// );
+    ///
+    /// let version = Version::parse("1!2020.01.06").unwrap();
+    /// assert_eq!(
+    ///     version.to_json(),
+    ///     r#"{"changeset":0,"day":6,"epoch":1,"label":null,"month":1,"year":2020}"#
+    /// );
+    /// ```
+    #[must_use]
+    pub fn to_json(&self) -> String {
+        let mut value = json!({
+            "year": self.date.year(),
+            "month": u8::from(self.date.month()),
+            "day": self.date.day(),
+            "changeset": self.changeset,
+            "label": self.label.as_ref().map(ToString::to_string),
+        });
+        if self.epoch > 0 {
+            value["epoch"] = self.epoch.into();
+        }
+        value.to_string()
+    }
+
+    /// Parse a version from JSON, either as plain string or in the object form that
+    /// [`to_json`](Self::to_json) creates. In the object form, `epoch`, `changeset` and `label`
+    /// are optional.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let expected = Version::parse("2020.01.06-test").unwrap();
+    ///
+    /// assert_eq!(Version::from_json(r#""2020.01.06-test""#).unwrap(), expected);
+    /// assert_eq!(
+    ///     Version::from_json(r#"{"year":2020,"month":1,"day":6,"label":"test"}"#).unwrap(),
+    ///     expected
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input is not valid JSON in either form, or describes an
+    /// invalid version.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip() {
+        for version in [
+            "2019.01.06",
+            "2019.01.06.1-break",
+            "2019.12.31-a.b.3",
+            "2!2019.01.06",
+        ] {
+            let version = Version::parse(version).unwrap();
+            assert_eq!(version, Version::from_json(&version.to_json()).unwrap());
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(Version::from_json(r#""2019.01""#).is_err());
+        assert!(Version::from_json(r#"{"year":2019,"month":13,"day":1}"#).is_err());
+        assert!(Version::from_json(r#"{"year":2019,"month":1}"#).is_err());
+        assert!(Version::from_json("2019").is_err());
+    }
+}
@@ -21,6 +21,7 @@
 //! use time::macros::date;
 //!
 //! assert!(Version::parse("2020.01.06") == Ok(Version {
+//!     epoch: 0,
 //!     date: date!(2020-01-06),
 //!     changeset: 0,
 //!     label: None,
@@ -39,7 +40,7 @@
 //! ```
 //!
 
-#![doc(html_root_url = "https://docs.rs/chronver/0.2.0")]
+#![doc(html_root_url = "https://docs.rs/chronver/0.2.1")]
 #![forbid(unsafe_code)]
 #![deny(clippy::all, clippy::pedantic)]
 #![warn(clippy::nursery)]
@@ -50,14 +51,90 @@
 )]
 
 use std::{
+    borrow::Cow,
     convert::TryFrom,
     fmt::{self, Display},
     str::FromStr,
+    time::SystemTime,
 };
 
 use thiserror::Error;
-use time::{format_description::FormatItem, macros::format_description, OffsetDateTime};
-pub use time::{Date, Month};
+use time::{
+    format_description::{well_known::Rfc3339, FormatItem},
+    macros::format_description,
+    OffsetDateTime,
+};
+pub use time::{Date, Month, UtcOffset, Weekday};
+
+pub use crate::{
+    ci::CiMetadata,
+    class::ReleaseClass,
+    clock::{Clock, SystemClock},
+    date::{DateExt, WeekendPolicy},
+    day::ReleaseDay,
+    display::{CanonicalDebug, VersionDisplay},
+    glob::FeatureGlob,
+    iter::{ChunkByDate, VersionIteratorExt},
+    order::{Descending, OrderingPolicy},
+    parser::{parse_all, Correction, LabelCharset, ParseOptions, Parser},
+    range::VersionRange,
+    req::{Comparator, Op, VersionReq},
+    scan::{FileNameVersion, FindVersions},
+    set::VersionSet,
+    span::SpannedError,
+    stable::StableVersion,
+    store::{FileStore, MemoryStore, StoreError, VersionStore},
+    validate::{Validator, Violation},
+    version_ref::{LabelRef, VersionRef},
+};
+
+#[cfg(feature = "ics")]
+pub mod ics;
+pub mod io;
+pub mod limits;
+pub mod report;
+pub mod schema;
+#[cfg(feature = "serde")]
+pub mod serde;
+pub mod stats;
+pub mod tag;
+pub mod testing;
+pub mod util;
+
+/// Implementation details of macros, not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::literal::Parts;
+}
+
+#[cfg(feature = "binary")]
+mod binary;
+mod bytes;
+mod ci;
+mod class;
+mod clock;
+mod date;
+mod day;
+mod display;
+mod glob;
+mod iter;
+#[cfg(feature = "json")]
+mod json;
+mod literal;
+mod metric;
+mod order;
+mod packed;
+mod parser;
+mod range;
+mod req;
+mod scan;
+mod set;
+mod sort_key;
+mod span;
+mod stable;
+mod store;
+mod validate;
+mod version_ref;
 
 /// An error type for this crate.
 #[derive(Error, Debug, Clone, Eq, PartialEq)]
@@ -77,17 +154,171 @@ pub enum ChronVerError {
     /// An error occurred while parsing the label component.
     #[error("Invalid label")]
     InvalidLabel,
+    /// The version bytes were not valid UTF-8.
+    #[error("Invalid UTF-8")]
+    InvalidUtf8(#[from] std::str::Utf8Error),
+    /// A changeset of 0 was given where an explicit changeset is required.
+    #[error("Changeset must not be zero")]
+    ZeroChangeset,
+    /// The changeset was smaller than the configured changeset offset.
+    #[error("Changeset is below the configured offset")]
+    ChangesetBelowOffset,
+    /// The label is not in the set of allowed labels.
+    #[error("Label `{0}` is not allowed")]
+    LabelNotAllowed(String),
+    /// The label is longer than [`limits::MAX_LABEL_LENGTH`].
+    #[error("Label is too long")]
+    LabelTooLong,
+    /// The label contains a character outside of the charset of [`ParseOptions::label_charset`].
+    #[error("Invalid character {0:?} in label")]
+    InvalidLabelChar(char),
+    /// A range literal was missing the `..` or the upper bound of an inclusive range.
+    #[error("Invalid version range")]
+    InvalidRange,
+    /// A changeset was given where none is allowed.
+    #[error("Unexpected changeset")]
+    UnexpectedChangeset,
+    /// A version requirement or one of its comparators was empty.
+    #[error("Invalid version requirement")]
+    InvalidRequirement,
+    /// The binary encoding of a version was malformed.
+    #[error("Invalid binary encoding")]
+    InvalidBinary,
+    /// A sort key was not in the format created by [`Version::to_sort_key`].
+    #[error("Invalid sort key")]
+    InvalidSortKey,
+    /// An error occurred while parsing the epoch prefix.
+    #[error("Invalid epoch")]
+    InvalidEpoch(#[source] std::num::ParseIntError),
+    /// The date was after the current date, while [`ParseOptions::deny_future`] was enabled.
+    #[error("Date {0} is in the future")]
+    FutureDate(Date),
+    /// The input is longer than allowed by [`ParseOptions::max_length`].
+    #[error("Version string is too long")]
+    InputTooLong,
+}
+
+impl ChronVerError {
+    /// Render the error together with all its underlying causes as a single line, separated by
+    /// colons. Causes that repeat the message of their parent are skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let err = Version::parse("2020.01.06.x").unwrap_err();
+    /// assert_eq!(err.full_message(), "Invalid changeset: cannot parse integer from empty string");
+    /// ```
+    #[must_use]
+    pub fn full_message(&self) -> String {
+        let mut message = self.to_string();
+        let mut previous = message.clone();
+        let mut source = std::error::Error::source(self);
+
+        while let Some(err) = source {
+            let current = err.to_string();
+            if current != previous {
+                message.push_str(": ");
+                message.push_str(&current);
+                previous = current;
+            }
+            source = err.source();
+        }
+
+        message
+    }
+
+    /// Get a machine-readable code for this error, like `invalid_month`. Unlike the message of
+    /// the error, the codes are stable: existing codes never change their meaning and are not
+    /// removed, so they can be mapped to API responses.
+    ///
+    /// Invalid dates are reported as `invalid_year`, `invalid_month` or `invalid_day` when a
+    /// single component is at fault, and as `invalid_date` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, Version};
+    ///
+    /// assert_eq!(Version::parse("2020.13.01").unwrap_err().code(), "invalid_month");
+    /// assert_eq!(Version::parse("2020.02.30").unwrap_err().code(), "invalid_day");
+    /// assert_eq!(Version::parse("2020.01.06.x").unwrap_err().code(), "invalid_changeset");
+    /// assert_eq!(ChronVerError::ZeroChangeset.code(), "changeset_zero");
+    /// ```
+    #[must_use]
+    pub fn code(&self) -> &'static str {
+        /// Pick the code for the date component that is at fault.
+        fn date_code(component: Option<&str>) -> &'static str {
+            match component {
+                Some("year") => "invalid_year",
+                Some("month") => "invalid_month",
+                Some("day") => "invalid_day",
+                _ => "invalid_date",
+            }
+        }
+
+        match self {
+            Self::TooShort => "too_short",
+            Self::InvalidVersion(e) => date_code(date_component(e)),
+            Self::InvalidComponents(e) => date_code(Some(e.name())),
+            Self::InvalidChangeset(_) => "invalid_changeset",
+            Self::InvalidLabel => "invalid_label",
+            Self::InvalidUtf8(_) => "invalid_utf8",
+            Self::ZeroChangeset => "changeset_zero",
+            Self::ChangesetBelowOffset => "changeset_below_offset",
+            Self::LabelNotAllowed(_) => "label_not_allowed",
+            Self::LabelTooLong => "label_too_long",
+            Self::InvalidLabelChar(_) => "invalid_label_char",
+            Self::InvalidRange => "invalid_range",
+            Self::UnexpectedChangeset => "unexpected_changeset",
+            Self::InvalidRequirement => "invalid_requirement",
+            Self::InvalidBinary => "invalid_binary",
+            Self::InvalidSortKey => "invalid_sort_key",
+            Self::InvalidEpoch(_) => "invalid_epoch",
+            Self::FutureDate(_) => "future_date",
+            Self::InputTooLong => "input_too_long",
+        }
+    }
+
+    /// Get a short hint on how to fix the input, for errors that are caused by a malformed
+    /// version. It complements the message of the error in user-facing reports, for example
+    /// together with the span of a [`SpannedError`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, Version};
+    ///
+    /// assert_eq!(
+    ///     Version::parse("2020.13.01").unwrap_err().help(),
+    ///     Some("month must be 01 to 12")
+    /// );
+    /// assert_eq!(ChronVerError::InvalidBinary.help(), None);
+    /// ```
+    #[must_use]
+    pub fn help(&self) -> Option<&'static str> {
+        Some(match self.code() {
+            "too_short" => "a version starts with a date like YYYY.MM.DD",
+            "invalid_year" => "year must have four digits, like 2020",
+            "invalid_month" => "month must be 01 to 12",
+            "invalid_day" => "day must exist in its month, like 01 to 31 in January",
+            "invalid_date" => "date must be in the format YYYY.MM.DD",
+            "invalid_changeset" => "changeset must be a number from 0 to 4294967295",
+            "invalid_label" => "a label must be separated by a dash, like 2020.01.06-beta",
+            "invalid_epoch" => "epoch must be a number from 0 to 4294967295, followed by `!`",
+            _ => return None,
+        })
+    }
 }
 
 /// Represents a version number conforming to the chronologic versioning scheme.
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(try_from = "&str"),
-    serde(into = "String")
-)]
 pub struct Version {
+    /// The epoch, to start a new era of versions that sorts above all versions of the previous
+    /// eras, for example after migrating from another versioning scheme. It is written as `N!`
+    /// prefix, like `1!2020.01.06`, and omitted if it is 0.
+    pub epoch: u32,
     /// The date of release, to be updated whenever a new release is made on a different date than
     /// the last release.
     pub date: Date,
@@ -118,6 +349,15 @@ macro_rules! ensure {
     };
 }
 
+/// Shorthand to return an error with its span when a condition is invalid.
+macro_rules! ensure_at {
+    ($cond:expr, $err:expr, $span:expr $(,)?) => {
+        if !$cond {
+            return Err(SpannedError::new($err, $span));
+        }
+    };
+}
+
 impl Version {
     /// Parse a string into a chronver object.
     ///
@@ -129,6 +369,7 @@ impl Version {
     ///
     /// // Basic version with just a date
     /// assert_eq!(Version::parse("2020.03.05"), Ok(Version {
+    ///     epoch: 0,
     ///     date: date!(2020-03-05),
     ///     changeset: 0,
     ///     label: None,
@@ -136,6 +377,7 @@ impl Version {
     ///
     /// // Version with a changeset
     /// assert_eq!(Version::parse("2020.03.05.2"), Ok(Version {
+    ///     epoch: 0,
     ///     date: date!(2020-03-05),
     ///     changeset: 2,
     ///     label: None,
@@ -143,50 +385,202 @@ impl Version {
     ///
     /// // And with label
     /// assert_eq!(Version::parse("2020.03.05.2-new"), Ok(Version {
+    ///     epoch: 0,
     ///     date: date!(2020-03-05),
     ///     changeset: 2,
     ///     label: Some(Label::Text("new".to_owned())),
     /// }));
+    ///
+    /// // And in a later epoch
+    /// assert_eq!(Version::parse("1!2020.03.05"), Ok(Version {
+    ///     epoch: 1,
+    ///     date: date!(2020-03-05),
+    ///     changeset: 0,
+    ///     label: None,
+    /// }));
     /// ```
     ///
     /// # Errors
     ///
-    /// An error can occur in two cases. First, when the very first part of the version is not a
-    /// valid date in the format `YYYY.MM.DD`. Second, when a **changeset** follows the date but
-    /// it is not a valid `u32` number.
+    /// An error can occur in three cases. First, when the very first part of the version, after
+    /// an optional `N!` epoch prefix, is not a valid date in the format `YYYY.MM.DD`. Second,
+    /// when a **changeset** follows the date but it is not a valid `u32` number. Third, when
+    /// the epoch is not a valid `u32` number.
     pub fn parse(version: &str) -> Result<Self, ChronVerError> {
-        ensure!(version.len() >= DATE_LENGTH, ChronVerError::TooShort);
+        Self::parse_with(version, &ParseOptions::default())
+    }
 
-        let date =
-            Date::parse(&version[..DATE_LENGTH], &DATE_FORMAT).map_err(ChronVerError::from)?;
+    /// Parse a string leniently into a chronver object, accepting common near-ChronVer forms
+    /// that are found in third-party changelogs. The result is the same as for the canonical
+    /// form of the input.
+    ///
+    /// In addition to the regular format, the following is accepted:
+    ///
+    /// - Whitespace around the version, like ` 2020.01.06\n`.
+    /// - Month and day without zero-padding, like `2020.1.6`.
+    /// - Dashes between the date components, like `2020-01-06`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let canonical = Version::parse("2020.01.06.2-beta").unwrap();
+    ///
+    /// assert_eq!(Version::parse_lenient("2020.1.6.2-beta"), Ok(canonical.clone()));
+    /// assert_eq!(Version::parse_lenient("2020-01-06.2-beta"), Ok(canonical.clone()));
+    /// assert_eq!(Version::parse_lenient("  2020-1-06.2-beta\n"), Ok(canonical));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur, if the input is not valid after
+    /// normalizing it.
+    pub fn parse_lenient(version: &str) -> Result<Self, ChronVerError> {
+        Self::parse_with(version, &ParseOptions::new().lenient(true))
+    }
 
-        let rem = &version[DATE_LENGTH..];
+    /// Parse a string into a chronver object, respecting the given options. This allows to opt
+    /// into exactly the strictness that is needed, without creating a [`Parser`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Version};
+    ///
+    /// let options = ParseOptions::new().lenient(true).underscores(true);
+    ///
+    /// assert_eq!(
+    ///     Version::parse_with("2020_1_6_2-beta", &options),
+    ///     Version::parse("2020.01.06.2-beta")
+    /// );
+    /// assert!(Version::parse_with("2020_1_6", &ParseOptions::new()).is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur, in addition to the errors of the
+    /// enabled options, like [`ChronVerError::LabelNotAllowed`] for
+    /// [`ParseOptions::allowed_labels`].
+    pub fn parse_with(version: &str, options: &ParseOptions) -> Result<Self, ChronVerError> {
+        Self::parse_spanned_with(version, options).map_err(SpannedError::into_error)
+    }
 
-        let (changeset, label_pos) = if let Some(rem) = rem.strip_prefix('.') {
-            let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
-            (rem[..end].parse().map_err(ChronVerError::from)?, end + 1)
-        } else {
-            ensure!(
-                rem.is_empty() || rem.starts_with('-'),
-                ChronVerError::InvalidLabel
-            );
-            (0, 0)
-        };
+    /// Parse a string into a chronver object, respecting the given options. Errors carry the
+    /// byte range of the offending part, relative to the input after normalizing it according
+    /// to the options.
+    fn parse_spanned_with(version: &str, options: &ParseOptions) -> Result<Self, SpannedError> {
+        check_length(version, options)?;
+        parse_ref(&normalize(version, options), options).map(Self::from)
+    }
+
+    /// Create a version from an RFC 3339 timestamp, like `2024-04-03T12:30:00Z`. The timestamp
+    /// is converted to the given offset first, and then truncated to its date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    /// use time::macros::offset;
+    ///
+    /// let version = Version::try_from_iso_datetime("2024-04-03T12:30:00Z", offset!(UTC)).unwrap();
+    /// assert_eq!(version.to_string(), "2024.04.03");
+    ///
+    /// let version = Version::try_from_iso_datetime("2024-04-03T22:30:00Z", offset!(+2)).unwrap();
+    /// assert_eq!(version.to_string(), "2024.04.04");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input is not a valid RFC 3339 timestamp.
+    pub fn try_from_iso_datetime(input: &str, offset: UtcOffset) -> Result<Self, ChronVerError> {
+        let datetime = OffsetDateTime::parse(input, &Rfc3339)?;
+        Ok(Self::from(datetime.to_offset(offset).date()))
+    }
 
-        let rem = &rem[label_pos..];
+    /// Create a version from a system time, for example the modification time of a file. The
+    /// time is converted to the given offset first, and then truncated to its date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::{Duration, SystemTime};
+    ///
+    /// use chronver::Version;
+    /// use time::macros::offset;
+    ///
+    /// let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_578_268_800);
+    /// assert_eq!(Version::from_system_time(time, offset!(UTC)).to_string(), "2020.01.06");
+    /// assert_eq!(Version::from_system_time(time, offset!(-1)).to_string(), "2020.01.05");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the system time is outside the range of dates that can be represented.
+    #[must_use]
+    pub fn from_system_time(time: SystemTime, offset: UtcOffset) -> Self {
+        Self::from(OffsetDateTime::from(time).to_offset(offset).date())
+    }
 
-        let label = if let Some(rem) = rem.strip_prefix('-') {
-            Some(rem.into())
-        } else {
-            ensure!(rem.is_empty(), ChronVerError::InvalidLabel);
-            None
+    /// Parse the longest valid version at the start of the input, returning the version and the
+    /// remaining, unparsed part of the input.
+    ///
+    /// As the label of a version is usually free-form, in this mode it is limited to ASCII
+    /// alphanumerics, `.`, `_` and `-`, so that other characters can separate the version from
+    /// any surrounding text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let (version, rest) = Version::parse_prefix("2024.04.03/linux-x86_64").unwrap();
+    /// assert_eq!(version.to_string(), "2024.04.03");
+    /// assert_eq!(rest, "/linux-x86_64");
+    ///
+    /// let (version, rest) = Version::parse_prefix("2024.04.03.2-beta.tar.gz").unwrap();
+    /// assert_eq!(version.to_string(), "2024.04.03.2-beta.tar.gz");
+    /// assert_eq!(rest, "");
+    ///
+    /// let (version, rest) = Version::parse_prefix("2024.04.03.x").unwrap();
+    /// assert_eq!(version.to_string(), "2024.04.03");
+    /// assert_eq!(rest, ".x");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input doesn't start with a valid date in the format
+    /// `YYYY.MM.DD`, or the changeset doesn't fit into a `u32`.
+    pub fn parse_prefix(input: &str) -> Result<(Self, &str), ChronVerError> {
+        let bytes = input.as_bytes();
+        let count = |start: usize, f: fn(&u8) -> bool| {
+            bytes
+                .get(start..)
+                .map_or(0, |rem| rem.iter().take_while(|b| f(b)).count())
         };
 
-        Ok(Self {
-            date,
-            changeset,
-            label,
-        })
+        let start = match count(0, u8::is_ascii_digit) {
+            len if len > 0 && bytes.get(len) == Some(&b'!') => len + 1,
+            _ => 0,
+        };
+        let mut end = start + DATE_LENGTH;
+
+        if bytes.get(end) == Some(&b'.') {
+            let len = count(end + 1, u8::is_ascii_digit);
+            if len > 0 {
+                end += 1 + len;
+            }
+        }
+
+        if bytes.get(end) == Some(&b'-') {
+            let len = count(end + 1, |&b| limits::is_label_char(b.into()));
+            if len > 0 {
+                end += 1 + len;
+            }
+        }
+
+        let version = Self::parse(input.get(..end).unwrap_or(input))?;
+        Ok((version, &input[end..]))
     }
 
     /// Update the version to the current date or increment the changeset in case the date
@@ -202,6 +596,186 @@ impl Version {
         self.label = None;
     }
 
+    /// Transform the date of this version with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    /// use time::Duration;
+    ///
+    /// let version = Version::parse("2020.01.06.1").unwrap().map_date(|d| d + Duration::days(1));
+    /// assert_eq!(version.to_string(), "2020.01.07.1");
+    /// ```
+    #[must_use]
+    pub fn map_date(self, f: impl FnOnce(Date) -> Date) -> Self {
+        Self {
+            date: f(self.date),
+            ..self
+        }
+    }
+
+    /// Transform the changeset of this version with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1-beta").unwrap().map_changeset(|_| 0);
+    /// assert_eq!(version.to_string(), "2020.01.06-beta");
+    /// ```
+    #[must_use]
+    pub fn map_changeset(self, f: impl FnOnce(u32) -> u32) -> Self {
+        Self {
+            changeset: f(self.changeset),
+            ..self
+        }
+    }
+
+    /// Transform the label of this version with the given function.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Label, Version};
+    ///
+    /// let version = Version::parse("2020.01.06.1").unwrap();
+    ///
+    /// let version = version.map_label(|_| Some(Label::parse("break")));
+    /// assert!(version.is_breaking());
+    ///
+    /// let version = version.map_label(|_| None);
+    /// assert_eq!(version.to_string(), "2020.01.06.1");
+    /// ```
+    #[must_use]
+    pub fn map_label(self, f: impl FnOnce(Option<Label>) -> Option<Label>) -> Self {
+        Self {
+            label: f(self.label),
+            ..self
+        }
+    }
+
+    /// Replace the changeset of this version with a raw counter value, for example read from an
+    /// external system.
+    ///
+    /// A value of `0` means the version has no changeset. If `allow_zero` is `false`, such a
+    /// value is rejected instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, Version};
+    ///
+    /// let version = Version::parse("2020.01.06").unwrap();
+    ///
+    /// assert_eq!(
+    ///     version.clone().checked_with_changeset(3, false).unwrap().to_string(),
+    ///     "2020.01.06.3"
+    /// );
+    /// assert_eq!(
+    ///     version.clone().checked_with_changeset(0, true).unwrap().to_string(),
+    ///     "2020.01.06"
+    /// );
+    /// assert_eq!(
+    ///     version.checked_with_changeset(0, false),
+    ///     Err(ChronVerError::ZeroChangeset)
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ChronVerError::ZeroChangeset`] if the changeset is `0` and zero is not allowed.
+    pub fn checked_with_changeset(
+        mut self,
+        changeset: u32,
+        allow_zero: bool,
+    ) -> Result<Self, ChronVerError> {
+        ensure!(allow_zero || changeset > 0, ChronVerError::ZeroChangeset);
+        self.changeset = changeset;
+        Ok(self)
+    }
+
+    /// Get the next version in the total order of unlabeled versions: the next changeset of the
+    /// same day, or the first version of the next day once the changeset is exhausted.
+    ///
+    /// Labeled versions sort between two unlabeled ones, so the successor of a labeled version is
+    /// the next changeset as well. Returns `None` if there is no later version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1-test").unwrap();
+    /// assert_eq!(version.succ().unwrap().to_string(), "2020.01.06.2");
+    ///
+    /// let version = Version::parse("2020.01.06.4294967295").unwrap();
+    /// assert_eq!(version.succ().unwrap().to_string(), "2020.01.07");
+    /// ```
+    #[must_use]
+    pub fn succ(&self) -> Option<Self> {
+        self.changeset
+            .checked_add(1)
+            .map(|changeset| Self {
+                changeset,
+                label: None,
+                ..self.clone()
+            })
+            .or_else(|| {
+                self.date.next_day().map(|date| Self {
+                    epoch: self.epoch,
+                    ..Self::from(date)
+                })
+            })
+    }
+
+    /// Get the previous version in the total order of unlabeled versions, which is the inverse
+    /// of [`Version::succ`] for unlabeled versions.
+    ///
+    /// For labeled versions, this is the same version without its label. Returns `None` if
+    /// there is no earlier version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.2").unwrap();
+    /// assert_eq!(version.pred().unwrap().to_string(), "2020.01.06.1");
+    ///
+    /// let version = Version::parse("2020.01.06.2-test").unwrap();
+    /// assert_eq!(version.pred().unwrap().to_string(), "2020.01.06.2");
+    ///
+    /// let version = Version::parse("2020.01.07").unwrap();
+    /// assert_eq!(version.pred().unwrap().to_string(), "2020.01.06.4294967295");
+    /// ```
+    #[must_use]
+    pub fn pred(&self) -> Option<Self> {
+        if self.label.is_some() {
+            return Some(Self {
+                label: None,
+                ..self.clone()
+            });
+        }
+
+        self.changeset
+            .checked_sub(1)
+            .map(|changeset| Self {
+                changeset,
+                label: None,
+                ..self.clone()
+            })
+            .or_else(|| {
+                self.date.previous_day().map(|date| Self {
+                    epoch: self.epoch,
+                    date,
+                    changeset: u32::MAX,
+                    label: None,
+                })
+            })
+    }
+
     /// Check whether the current version introduces breaking changes.
     ///
     /// # Examples
@@ -214,17 +788,14 @@ impl Version {
     /// ```
     #[must_use]
     pub fn is_breaking(&self) -> bool {
-        if let Some(Label::Text(label)) = &self.label {
-            return label == BREAK_LABEL;
-        }
-        false
+        self.label.as_ref().and_then(Label::as_str) == Some(BREAK_LABEL)
     }
 }
 
 impl Default for Version {
-    #[must_use]
     fn default() -> Self {
         Self {
+            epoch: 0,
             date: OffsetDateTime::now_utc().date(),
             changeset: 0,
             label: None,
@@ -242,21 +813,45 @@ impl FromStr for Version {
 
 impl Display for Version {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        f.write_str(&self.date.format(&DATE_FORMAT).map_err(|_| fmt::Error)?)?;
-        if self.changeset > 0 {
-            write!(f, ".{}", self.changeset)?;
-        }
-        if let Some(label) = &self.label {
-            write!(f, "-{label}")?;
-        }
-        Ok(())
+        VersionDisplay::new(self).fmt(f)
+    }
+}
+
+impl Version {
+    /// Feed the canonical string form of this version (as produced by its [`Display`]
+    /// implementation) into the hasher.
+    ///
+    /// Unlike the derived [`Hash`] implementation, which depends on the internal representation,
+    /// the bytes written here are guaranteed to stay the same across releases of this crate. That
+    /// makes it suitable for persistent, content-addressed keys.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::collections::hash_map::DefaultHasher;
+    /// use std::hash::Hasher;
+    ///
+    /// use chronver::Version;
+    ///
+    /// let hash = |v: &str| {
+    ///     let mut hasher = DefaultHasher::new();
+    ///     Version::parse(v).unwrap().hash_stable_into(&mut hasher);
+    ///     hasher.finish()
+    /// };
+    ///
+    /// // The zero changeset is not part of the canonical form.
+    /// assert_eq!(hash("2020.01.06.0"), hash("2020.01.06"));
+    /// assert_ne!(hash("2020.01.06.1"), hash("2020.01.06"));
+    /// ```
+    pub fn hash_stable_into(&self, state: &mut impl std::hash::Hasher) {
+        state.write(self.to_string().as_bytes());
     }
 }
 
 impl From<Date> for Version {
-    #[must_use]
     fn from(date: Date) -> Self {
         Self {
+            epoch: 0,
             date,
             changeset: 0,
             label: None,
@@ -264,6 +859,29 @@ impl From<Date> for Version {
     }
 }
 
+impl From<Version> for Date {
+    #[inline]
+    fn from(version: Version) -> Self {
+        version.date
+    }
+}
+
+impl From<Version> for SystemTime {
+    /// Convert the version into the point in time at midnight UTC of its release date.
+    #[inline]
+    fn from(version: Version) -> Self {
+        OffsetDateTime::from(version).into()
+    }
+}
+
+impl From<Version> for OffsetDateTime {
+    /// Convert the version into the point in time at midnight UTC of its release date.
+    #[inline]
+    fn from(version: Version) -> Self {
+        version.date.midnight().assume_utc()
+    }
+}
+
 impl TryFrom<(i32, Month, u8)> for Version {
     type Error = ChronVerError;
 
@@ -285,7 +903,6 @@ impl TryFrom<&str> for Version {
 
 impl From<Version> for String {
     #[inline]
-    #[must_use]
     fn from(version: Version) -> Self {
         format!("{version}")
     }
@@ -293,12 +910,6 @@ impl From<Version> for String {
 
 /// A label in the version metadata.
 #[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
-#[cfg_attr(
-    feature = "serde",
-    derive(serde::Serialize, serde::Deserialize),
-    serde(from = "&str"),
-    serde(into = "String")
-)]
 pub enum Label {
     /// A simple text label without a specific format.
     Text(String),
@@ -328,16 +939,62 @@ impl Label {
     /// ```
     #[must_use]
     pub fn parse(label: &str) -> Self {
-        if let Some(i) = label.rfind('.') {
-            if let Ok(changeset) = label[i + 1..].parse() {
-                return Self::Feature {
-                    branch: label[..i].to_owned(),
-                    changeset,
-                };
-            }
+        LabelRef::parse(label).into()
+    }
+
+    /// Get the branch name of this label. For a plain [`Label::Text`] that is the whole text.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Label;
+    ///
+    /// assert_eq!(Label::parse("login.2").branch(), "login");
+    /// assert_eq!(Label::parse("login").branch(), "login");
+    /// ```
+    #[must_use]
+    pub fn branch(&self) -> &str {
+        match self {
+            Self::Text(branch) | Self::Feature { branch, .. } => branch,
+        }
+    }
+
+    /// Get the label as string slice, if it consists of a single text. A feature label is made
+    /// up of its branch and changeset, so it has no single string to borrow and `None` is
+    /// returned instead.
+    ///
+    /// This allows to match on labels without allocating a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Label;
+    ///
+    /// assert_eq!(Label::parse("break").as_str(), Some("break"));
+    /// assert_eq!(Label::parse("login.2").as_str(), None);
+    /// ```
+    #[must_use]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::Text(text) => Some(text),
+            Self::Feature { .. } => None,
         }
+    }
 
-        Self::Text(label.to_owned())
+    /// Check whether this label matches the given glob pattern (see [`FeatureGlob`] for the
+    /// supported syntax).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Label;
+    ///
+    /// assert!(Label::parse("release/2020.1").matches_glob("release/*"));
+    /// assert!(!Label::parse("hotfix/2020.1").matches_glob("release/*"));
+    /// ```
+    #[must_use]
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        FeatureGlob::new(pattern).matches_label(self)
     }
 }
 
@@ -352,20 +1009,298 @@ impl Display for Label {
 
 impl From<&str> for Label {
     #[inline]
-    #[must_use]
     fn from(s: &str) -> Self {
         Self::parse(s)
     }
 }
 
+impl FromStr for Label {
+    type Err = std::convert::Infallible;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::parse(s))
+    }
+}
+
 impl From<Label> for String {
     #[inline]
-    #[must_use]
     fn from(label: Label) -> Self {
         format!("{label}")
     }
 }
 
+/// Split off the optional epoch prefix, like the `1!` in `1!2020.01.06`, returning the epoch and
+/// the rest of the version. Versions without prefix are in epoch 0.
+fn split_epoch(version: &str) -> Result<(u32, &str), ChronVerError> {
+    match version.split_once('!') {
+        Some((epoch, rest)) if !epoch.is_empty() && epoch.bytes().all(|b| b.is_ascii_digit()) => {
+            Ok((epoch.parse().map_err(ChronVerError::InvalidEpoch)?, rest))
+        }
+        _ => Ok((0, version)),
+    }
+}
+
+/// Check the raw input against the maximum length of the options, before doing any work on it.
+pub(crate) const fn check_length(
+    version: &str,
+    options: &ParseOptions,
+) -> Result<(), SpannedError> {
+    if let Some(max) = options.max_length {
+        ensure_at!(
+            version.len() <= max,
+            ChronVerError::InputTooLong,
+            max..version.len()
+        );
+    }
+    Ok(())
+}
+
+/// Parse a version out of the already normalized input, respecting the remaining options. This
+/// is the grammar behind all parsing functions, borrowing the label from the input.
+pub(crate) fn parse_ref<'a>(
+    full: &'a str,
+    options: &ParseOptions,
+) -> Result<VersionRef<'a>, SpannedError> {
+    // All the remaining parts are suffixes of the full input, so their position follows
+    // from their length.
+    let offset = |rest: &str| full.len() - rest.len();
+
+    let version = if options.v_prefix {
+        full.strip_prefix(|c| c == 'v' || c == 'V').unwrap_or(full)
+    } else {
+        full
+    };
+    let (epoch, version) = split_epoch(version).map_err(|e| {
+        let start = offset(version);
+        SpannedError::new(e, start..start + version.find('!').unwrap_or_default())
+    })?;
+
+    let date_start = offset(version);
+    ensure_at!(
+        version.len() >= DATE_LENGTH,
+        ChronVerError::TooShort,
+        date_start..full.len()
+    );
+
+    // If the date part ends in the middle of a multi-byte character, parsing the whole input
+    // is guaranteed to fail as well, but without panicking on the split.
+    let date_input = version.get(..DATE_LENGTH).unwrap_or(version);
+    let date = Date::parse(date_input, &DATE_FORMAT).map_err(|e| {
+        let span = date_error_span(date_input, &e);
+        SpannedError::new(e.into(), date_start + span.start..date_start + span.end)
+    })?;
+    let date_span = date_start..date_start + DATE_LENGTH;
+    options
+        .check_date(date)
+        .map_err(|e| SpannedError::new(e, date_span))?;
+
+    let rem = &version[DATE_LENGTH..];
+
+    let (changeset, label_pos) = if let Some(rem) = rem.strip_prefix('.') {
+        let start = offset(rem);
+        let end = rem.find(|c: char| !c.is_ascii_digit()).unwrap_or(rem.len());
+        // Point at the unexpected character, if there are no digits at all.
+        let span_end = if end == 0 {
+            rem.chars().next().map_or(0, char::len_utf8)
+        } else {
+            end
+        };
+        let span = start..start + span_end;
+        let changeset = rem[..end]
+            .parse::<u32>()
+            .map_err(|e| SpannedError::new(ChronVerError::from(e), span.clone()))?;
+        // Only explicit changesets continue the previous counter, while an omitted one always
+        // stands for the first release of the day.
+        let changeset = changeset
+            .checked_sub(options.changeset_offset)
+            .ok_or_else(|| SpannedError::new(ChronVerError::ChangesetBelowOffset, span))?;
+        (changeset, end + 1)
+    } else {
+        ensure_at!(
+            rem.is_empty() || rem.starts_with('-'),
+            ChronVerError::InvalidLabel,
+            offset(rem)..full.len()
+        );
+        (0, 0)
+    };
+
+    let rem = &rem[label_pos..];
+
+    let label = if let Some(rem) = rem.strip_prefix('-') {
+        Some(parse_label(rem, offset(rem), options)?)
+    } else {
+        ensure_at!(
+            rem.is_empty(),
+            ChronVerError::InvalidLabel,
+            offset(rem)..full.len()
+        );
+        None
+    };
+
+    Ok(VersionRef {
+        epoch,
+        date,
+        changeset,
+        label,
+    })
+}
+
+/// Parse the label of a version, that starts at the given byte offset of the input.
+fn parse_label<'a>(
+    input: &'a str,
+    start: usize,
+    options: &ParseOptions,
+) -> Result<LabelRef<'a>, SpannedError> {
+    let label_span = start..start + input.len();
+    let char_span = |(i, c): (usize, char)| {
+        let start = start + i;
+        start..start + c.len_utf8()
+    };
+
+    if let Some(max) = options.max_label_length {
+        ensure_at!(input.len() <= max, ChronVerError::LabelTooLong, label_span);
+    }
+    if options.strict {
+        ensure_at!(
+            input.len() <= limits::MAX_LABEL_LENGTH,
+            ChronVerError::LabelTooLong,
+            label_span
+        );
+        if let Some((i, c)) = input
+            .char_indices()
+            .find(|&(_, c)| !options.label_charset.allows(c))
+        {
+            return Err(SpannedError::new(
+                ChronVerError::InvalidLabelChar(c),
+                char_span((i, c)),
+            ));
+        }
+    }
+
+    let label = match LabelRef::parse(input) {
+        LabelRef::Text(text)
+            if options.break_ignore_case && text.eq_ignore_ascii_case(BREAK_LABEL) =>
+        {
+            LabelRef::Text(BREAK_LABEL)
+        }
+        label => label,
+    };
+    if let Some(allowed) = &options.allowed_labels {
+        let branch = match label {
+            LabelRef::Text(branch) | LabelRef::Feature { branch, .. } => branch,
+        };
+        ensure_at!(
+            allowed.iter().any(|l| l == branch),
+            ChronVerError::LabelNotAllowed(branch.to_owned()),
+            label_span.start..label_span.start + branch.len()
+        );
+    }
+    Ok(label)
+}
+
+/// Get the name of the date component that caused the parse error, if it was caused by a
+/// single component.
+const fn date_component(error: &time::error::Parse) -> Option<&'static str> {
+    use time::error::{Parse, ParseFromDescription, TryFromParsed};
+
+    match error {
+        Parse::TryFromParsed(TryFromParsed::ComponentRange(e)) => Some(e.name()),
+        Parse::ParseFromDescription(ParseFromDescription::InvalidComponent(name)) => Some(name),
+        _ => None,
+    }
+}
+
+/// Locate the component of a date in the format `YYYY.MM.DD`, that caused the parse error.
+fn date_error_span(date: &str, error: &time::error::Parse) -> std::ops::Range<usize> {
+    let span = match date_component(error) {
+        Some("year") => 0..4,
+        Some("month") => 5..7,
+        Some("day") => 8..10,
+        _ => match date
+            .bytes()
+            .enumerate()
+            .find(|&(i, b)| (i == 4 || i == 7) && b != b'.')
+        {
+            Some((i, _)) => i..i + 1,
+            None => 0..DATE_LENGTH,
+        },
+    };
+
+    // The date part is longer than expected, if it ended in a multi-byte character, which
+    // must not be split by the span.
+    let end = (span.end..=date.len())
+        .find(|&end| date.is_char_boundary(end))
+        .unwrap_or(date.len());
+    span.start..end
+}
+
+/// Normalize the input according to the underscore and lenient options, before it's parsed.
+fn normalize<'a>(version: &'a str, options: &ParseOptions) -> Cow<'a, str> {
+    let version = if options.underscores {
+        normalize_underscores(version)
+    } else {
+        Cow::Borrowed(version)
+    };
+
+    if options.lenient {
+        Cow::Owned(normalize_lenient(&version).into_owned())
+    } else {
+        version
+    }
+}
+
+/// Replace underscores in the date and changeset part of a version with dots, leaving the label
+/// untouched.
+fn normalize_underscores(version: &str) -> Cow<'_, str> {
+    let end = version.find('-').unwrap_or(version.len());
+    let (numbers, label) = version.split_at(end);
+
+    if numbers.contains('_') {
+        Cow::Owned(numbers.replace('_', ".") + label)
+    } else {
+        Cow::Borrowed(version)
+    }
+}
+
+/// Normalize common near-ChronVer forms into the canonical format, by trimming surrounding
+/// whitespace, zero-padding month and day, and replacing dashes between the date components
+/// with dots. Inputs that don't start with such a date are only trimmed.
+fn normalize_lenient(version: &str) -> Cow<'_, str> {
+    let version = version.trim();
+    let digits = |s: &str| s.bytes().take_while(u8::is_ascii_digit).count();
+
+    let epoch = match digits(version) {
+        len if len > 0 && version[len..].starts_with('!') => len + 1,
+        _ => 0,
+    };
+    let (epoch, rest) = version.split_at(epoch);
+
+    let (year, rest) = rest.split_at(digits(rest));
+    let separator = match rest.chars().next() {
+        Some(c @ ('.' | '-')) if year.len() == 4 => c,
+        _ => return Cow::Borrowed(version),
+    };
+    let rest = &rest[1..];
+
+    let (month, rest) = rest.split_at(digits(rest));
+    let rest = match rest.strip_prefix(separator) {
+        Some(rest) if (1..=2).contains(&month.len()) => rest,
+        _ => return Cow::Borrowed(version),
+    };
+
+    let (day, rest) = rest.split_at(digits(rest));
+    if !(1..=2).contains(&day.len()) {
+        return Cow::Borrowed(version);
+    }
+
+    if separator == '.' && month.len() == 2 && day.len() == 2 {
+        Cow::Borrowed(version)
+    } else {
+        Cow::Owned(format!("{epoch}{year}.{month:0>2}.{day:0>2}{rest}"))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use time::macros::date;
@@ -383,6 +1318,7 @@ mod tests {
         let version = Version::parse("2019.01.06.12");
         assert_eq!(
             Version {
+                epoch: 0,
                 date: date!(2019 - 01 - 06),
                 changeset: 12,
                 label: None
@@ -402,6 +1338,7 @@ mod tests {
         let version = Version::parse("2019.01.06-test");
         assert_eq!(
             Version {
+                epoch: 0,
                 date: date!(2019 - 01 - 06),
                 changeset: 0,
                 label: Some(Label::Text("test".to_owned()))
@@ -415,6 +1352,7 @@ mod tests {
         let version = Version::parse("2019.01.06.1-test");
         assert_eq!(
             Version {
+                epoch: 0,
                 date: date!(2019 - 01 - 06),
                 changeset: 1,
                 label: Some(Label::Text("test".to_owned()))
@@ -428,6 +1366,7 @@ mod tests {
         let version = Version::parse("2019.01.06.0-test");
         assert_eq!(
             Version {
+                epoch: 0,
                 date: date!(2019 - 01 - 06),
                 changeset: 0,
                 label: Some(Label::Text("test".to_owned()))
@@ -436,6 +1375,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn with_epoch() {
+        let version = Version::parse("2!2019.01.06.1-test").unwrap();
+        assert_eq!(2, version.epoch);
+        assert_eq!(Some(Label::Text("test".to_owned())), version.label);
+        assert_eq!("2!2019.01.06.1-test", version.to_string());
+        assert_eq!(version, Version::parse(&version.to_string()).unwrap());
+
+        assert_eq!(0, Version::parse("0!2019.01.06").unwrap().epoch);
+        assert_eq!(
+            "2019.01.06",
+            Version::parse("0!2019.01.06").unwrap().to_string()
+        );
+        let v = |v: &str| Version::parse(v).unwrap();
+        assert!(v("1!2019.01.06") > v("2030.12.31.9-z"));
+        assert!(v("1!2019.01.06") < v("2!2000.01.01"));
+
+        assert!(matches!(
+            Version::parse("4294967296!2019.01.06").unwrap_err(),
+            ChronVerError::InvalidEpoch(_)
+        ));
+        assert!(matches!(
+            Version::parse("!2019.01.06").unwrap_err(),
+            ChronVerError::InvalidVersion(_)
+        ));
+        assert_eq!(
+            "wow!",
+            Version::parse("2019.01.06-wow!")
+                .unwrap()
+                .label
+                .unwrap()
+                .to_string()
+        );
+    }
+
     #[test]
     fn too_short() {
         let version = Version::parse("2019");
@@ -451,6 +1425,198 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn into_time_types() {
+        let version = Version::parse("2019.01.06.2-test").unwrap();
+        assert_eq!(date!(2019 - 01 - 06), Date::from(version.clone()));
+        assert_eq!(
+            time::macros::datetime!(2019-01-06 0:00 UTC),
+            OffsetDateTime::from(version)
+        );
+    }
+
+    #[test]
+    fn system_time() {
+        let version = Version::parse("2019.01.06.2-test").unwrap();
+        let time = SystemTime::from(version);
+
+        assert_eq!(
+            std::time::Duration::from_secs(1_546_732_800),
+            time.duration_since(SystemTime::UNIX_EPOCH).unwrap()
+        );
+        assert_eq!(
+            Version::parse("2019.01.06").unwrap(),
+            Version::from_system_time(time, UtcOffset::UTC)
+        );
+    }
+
+    #[test]
+    fn multi_byte_date() {
+        assert!(matches!(
+            Version::parse("2019.01.0\u{e9}").unwrap_err(),
+            ChronVerError::InvalidVersion(_)
+        ));
+        assert!(matches!(
+            Version::parse_prefix("2019.01.0\u{e9}").unwrap_err(),
+            ChronVerError::InvalidVersion(_)
+        ));
+    }
+
+    #[test]
+    fn error_codes() {
+        for (input, code) in [
+            ("2019", "too_short"),
+            ("x019.01.06", "invalid_year"),
+            ("2019.00.06", "invalid_month"),
+            ("2019.1x.06", "invalid_month"),
+            ("2019.01.00", "invalid_day"),
+            ("2019.02.29", "invalid_day"),
+            ("2019-01.06", "invalid_date"),
+            ("2019.01.06.", "invalid_changeset"),
+            ("2019.01.06+a", "invalid_label"),
+            ("99999999999!2019.01.06", "invalid_epoch"),
+        ] {
+            let err = Version::parse(input).unwrap_err();
+            assert_eq!(code, err.code(), "{input:?}");
+            assert!(err.help().is_some(), "{input:?}");
+        }
+
+        assert_eq!(
+            "invalid_month",
+            ChronVerError::from(Month::try_from(13).unwrap_err()).code()
+        );
+        assert_eq!(
+            "future_date",
+            ChronVerError::FutureDate(date!(2019 - 01 - 06)).code()
+        );
+        assert_eq!(None, ChronVerError::InvalidSortKey.help());
+    }
+
+    #[test]
+    fn parse_lenient() {
+        for (input, expected) in [
+            ("2019.1.6", "2019.01.06"),
+            ("2019.01.6.2", "2019.01.06.2"),
+            ("2019-01-06", "2019.01.06"),
+            ("2019-1-6-beta", "2019.01.06-beta"),
+            ("\t2019.01.06.1-a.1 \n", "2019.01.06.1-a.1"),
+            ("1!2019-1-6", "1!2019.01.06"),
+            ("2019.01.06-1-2", "2019.01.06-1-2"),
+        ] {
+            assert_eq!(
+                Version::parse(expected),
+                Version::parse_lenient(input),
+                "{input:?}"
+            );
+        }
+
+        for input in [
+            "2019.1-6",
+            "2019-01.06",
+            "19.1.6",
+            "2019.001.06",
+            "2019.1.6x",
+            "",
+        ] {
+            assert!(Version::parse_lenient(input).is_err(), "{input:?}");
+        }
+        assert!(Version::parse(" 2019.01.06").is_err());
+    }
+
+    #[test]
+    fn parse_prefix() {
+        let (version, rest) = Version::parse_prefix("2019.01.06.1-test/x").unwrap();
+        assert_eq!(Version::parse("2019.01.06.1-test").unwrap(), version);
+        assert_eq!("/x", rest);
+
+        let (version, rest) = Version::parse_prefix("2019.01.06- text").unwrap();
+        assert_eq!(Version::parse("2019.01.06").unwrap(), version);
+        assert_eq!("- text", rest);
+
+        let (version, rest) = Version::parse_prefix("1!2019.01.06.2 text").unwrap();
+        assert_eq!(Version::parse("1!2019.01.06.2").unwrap(), version);
+        assert_eq!(" text", rest);
+
+        assert_eq!(
+            ChronVerError::TooShort,
+            Version::parse_prefix("2019.01").unwrap_err()
+        );
+    }
+
+    #[test]
+    fn padded() {
+        let version = Version::parse("2019.01.06.12").unwrap();
+        assert_eq!("2019.01.06.012", version.padded(3).to_string());
+        assert_eq!("2019.01.06.12", version.padded(1).to_string());
+        assert_eq!(version, Version::parse("2019.01.06.012").unwrap());
+    }
+
+    #[test]
+    fn succ_and_pred() {
+        let version = Version::parse("2019.01.06.3").unwrap();
+        assert_eq!(Some(version.clone()), version.succ().unwrap().pred());
+        assert_eq!(Some(version.clone()), version.pred().unwrap().succ());
+        assert!(version < version.succ().unwrap());
+
+        let labeled = Version::parse("2019.01.06.3-test").unwrap();
+        assert!(labeled < labeled.succ().unwrap());
+        assert!(labeled > labeled.pred().unwrap());
+
+        assert_eq!(
+            None,
+            Version {
+                epoch: 0,
+                date: Date::MAX,
+                changeset: u32::MAX,
+                label: None
+            }
+            .succ()
+        );
+        assert_eq!(None, Version::from(Date::MIN).pred());
+    }
+
+    #[test]
+    fn iso_datetime() {
+        let utc = UtcOffset::UTC;
+        let west = UtcOffset::from_hms(-5, 0, 0).unwrap();
+
+        assert_eq!(
+            Version::from(date!(2019 - 01 - 06)),
+            Version::try_from_iso_datetime("2019-01-06T00:00:00+00:00", utc).unwrap()
+        );
+        assert_eq!(
+            Version::from(date!(2019 - 01 - 05)),
+            Version::try_from_iso_datetime("2019-01-06T03:00:00Z", west).unwrap()
+        );
+        assert!(matches!(
+            Version::try_from_iso_datetime("2019-01-06", utc).unwrap_err(),
+            ChronVerError::InvalidVersion(_)
+        ));
+    }
+
+    #[test]
+    fn ordering_precedence() {
+        let parse = |v| Version::parse(v).unwrap();
+
+        assert!(parse("2019.01.06.9-z") < parse("2019.01.07"));
+        assert!(parse("2019.01.06-z") < parse("2019.01.06.1"));
+        assert!(parse("2019.01.06") < parse("2019.01.06-a"));
+        assert!(parse("2019.01.06-a") < parse("2019.01.06-b"));
+    }
+
+    #[test]
+    fn full_message() {
+        let err = Version::parse("2019.30.01").unwrap_err();
+        assert_eq!(
+            "Invalid version string: the 'month' component could not be parsed",
+            err.full_message()
+        );
+        assert_eq!(
+            "Version string is too short",
+            ChronVerError::TooShort.full_message()
+        );
+    }
+
     #[test]
     fn invalid_changeset() {
         let version = Version::parse("2019.01.06+111");
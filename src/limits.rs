@@ -0,0 +1,103 @@
+//! Limits for labels, that are enforced with [`ParseOptions::strict`], and for untrusted input.
+//!
+//! The limits keep every version of the default epoch usable as git tag and as OCI image tag,
+//! which allows at most 128 characters out of ASCII alphanumerics, `.`, `_` and `-`.
+//!
+//! [`ParseOptions::strict`]: crate::ParseOptions::strict
+
+/// Maximum length of a label in bytes, including the changeset of a feature label.
+pub const MAX_LABEL_LENGTH: usize = 96;
+
+/// Maximum length of a version in bytes, when its label stays within [`MAX_LABEL_LENGTH`].
+///
+/// This is the largest possible epoch, the date, the largest possible changeset and the label,
+/// with their separators.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{limits, Version};
+///
+/// let label = "a".repeat(limits::MAX_LABEL_LENGTH);
+/// let version = Version::parse(&format!("{0}!9999.12.31.{0}-{label}", u32::MAX)).unwrap();
+///
+/// assert_eq!(version.to_string().len(), limits::MAX_VERSION_LENGTH);
+/// ```
+pub const MAX_VERSION_LENGTH: usize = 10 + 1 + 10 + 1 + 10 + 1 + MAX_LABEL_LENGTH;
+
+/// Suggested maximum length of untrusted input in bytes, for [`ParseOptions::max_length`]. It
+/// leaves enough room for any version within the other limits.
+///
+/// [`ParseOptions::max_length`]: crate::ParseOptions::max_length
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{limits, ChronVerError, ParseOptions, Parser};
+///
+/// let parser = Parser::new(ParseOptions::new().max_length(limits::MAX_INPUT_LENGTH));
+/// let input = "9".repeat(limits::MAX_INPUT_LENGTH + 1);
+/// assert_eq!(parser.parse(&input), Err(ChronVerError::InputTooLong));
+/// ```
+pub const MAX_INPUT_LENGTH: usize = 256;
+
+/// Characters that are allowed in labels in addition to ASCII alphanumerics.
+pub const LABEL_SPECIAL_CHARS: [char; 3] = ['.', '_', '-'];
+
+/// Check whether the character is allowed in a label.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::limits;
+///
+/// assert!(limits::is_label_char('a'));
+/// assert!(limits::is_label_char('_'));
+/// assert!(!limits::is_label_char('/'));
+/// assert!(!limits::is_label_char('ü'));
+/// ```
+#[must_use]
+pub fn is_label_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || LABEL_SPECIAL_CHARS.contains(&c)
+}
+
+/// Check whether the character is allowed in a label, when labels are limited to
+/// `[a-z0-9._-]` with [`LabelCharset::Portable`].
+///
+/// [`LabelCharset::Portable`]: crate::LabelCharset::Portable
+///
+/// # Examples
+///
+/// ```
+/// use chronver::limits;
+///
+/// assert!(limits::is_portable_label_char('a'));
+/// assert!(limits::is_portable_label_char('.'));
+/// assert!(!limits::is_portable_label_char('A'));
+/// assert!(!limits::is_portable_label_char('+'));
+/// ```
+#[must_use]
+pub fn is_portable_label_char(c: char) -> bool {
+    c.is_ascii_lowercase() || c.is_ascii_digit() || LABEL_SPECIAL_CHARS.contains(&c)
+}
+
+/// Check whether the character is allowed in a label, when non-ASCII alphanumerics are accepted
+/// with [`LabelCharset::Unicode`].
+///
+/// [`LabelCharset::Unicode`]: crate::LabelCharset::Unicode
+///
+/// # Examples
+///
+/// ```
+/// use chronver::limits;
+///
+/// assert!(limits::is_unicode_label_char('ü'));
+/// assert!(limits::is_unicode_label_char('名'));
+/// assert!(limits::is_unicode_label_char('-'));
+/// assert!(!limits::is_unicode_label_char('/'));
+/// assert!(!limits::is_unicode_label_char('\u{a0}'));
+/// ```
+#[must_use]
+pub fn is_unicode_label_char(c: char) -> bool {
+    is_label_char(c) || c.is_alphanumeric()
+}
@@ -0,0 +1,248 @@
+//! Compile-time checked version literals, through the [`chronver!`](crate::chronver) macro and
+//! [`Version::parse_const`].
+
+use time::{Date, Month};
+
+use crate::{Label, Version};
+
+/// Create a [`Version`] from a string literal, that is validated at compile time.
+///
+/// The date and changeset are computed in a constant, so an invalid literal fails to compile
+/// instead of panicking at runtime.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{chronver, Version};
+///
+/// assert_eq!(chronver!("2020.01.06"), Version::parse("2020.01.06").unwrap());
+/// assert_eq!(
+///     chronver!("2020.01.06.3-break"),
+///     Version::parse("2020.01.06.3-break").unwrap()
+/// );
+/// ```
+///
+/// Invalid versions are rejected by the compiler:
+///
+/// ```compile_fail
+/// let version = chronver::chronver!("2020.02.30");
+/// ```
+#[macro_export]
+macro_rules! chronver {
+    ($version:literal) => {{
+        const PARTS: $crate::__private::Parts = $crate::__private::Parts::parse($version);
+        PARTS.build($version)
+    }};
+}
+
+/// The components of a version literal, with the label given as its position in the literal.
+#[derive(Debug, Clone, Copy)]
+pub struct Parts {
+    /// The epoch.
+    epoch: u32,
+    /// The release date.
+    date: Date,
+    /// The changeset number.
+    changeset: u32,
+    /// Byte position where the label starts, if there is one.
+    label: Option<usize>,
+}
+
+impl Parts {
+    /// Split a version literal into its components.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the literal is not a valid version, which fails compilation when called in a
+    /// constant.
+    #[must_use]
+    pub const fn parse(version: &str) -> Self {
+        let bytes = version.as_bytes();
+        let (epoch, start) = match number(bytes, 0) {
+            (epoch, end) if end > 0 && end < bytes.len() && bytes[end] == b'!' => (epoch, end + 1),
+            _ => (0, 0),
+        };
+        assert!(
+            bytes.len() >= start + 10 && bytes[start + 4] == b'.' && bytes[start + 7] == b'.',
+            "version must start with a date in the format `YYYY.MM.DD`"
+        );
+
+        let month = match two_digits(bytes, start + 5) {
+            1 => Month::January,
+            2 => Month::February,
+            3 => Month::March,
+            4 => Month::April,
+            5 => Month::May,
+            6 => Month::June,
+            7 => Month::July,
+            8 => Month::August,
+            9 => Month::September,
+            10 => Month::October,
+            11 => Month::November,
+            12 => Month::December,
+            _ => panic!("month must be between 1 and 12"),
+        };
+        let year = two_digits(bytes, start) as i32 * 100 + two_digits(bytes, start + 2) as i32;
+        let date = match Date::from_calendar_date(year, month, two_digits(bytes, start + 8)) {
+            Ok(date) => date,
+            Err(_) => panic!("day is out of range for the month"),
+        };
+
+        let mut pos = start + 10;
+        let mut changeset = 0_u32;
+        if pos < bytes.len() && bytes[pos] == b'.' {
+            let (value, end) = number(bytes, pos + 1);
+            assert!(end > pos + 1, "changeset must not be empty");
+            changeset = value;
+            pos = end;
+        }
+
+        let label = if pos == bytes.len() {
+            None
+        } else if bytes[pos] == b'-' {
+            Some(pos + 1)
+        } else {
+            panic!("label must be separated with `-`");
+        };
+
+        Self {
+            epoch,
+            date,
+            changeset,
+            label,
+        }
+    }
+
+    /// Create the version, taking the label from the original literal.
+    #[must_use]
+    pub fn build(self, version: &str) -> Version {
+        Version {
+            epoch: self.epoch,
+            date: self.date,
+            changeset: self.changeset,
+            label: self.label.map(|pos| Label::parse(&version[pos..])),
+        }
+    }
+}
+
+impl Version {
+    /// Parse a version in a `const` context, for example from a string that a build script
+    /// generated. Labels need an allocation, so only versions without label are supported. Use
+    /// the [`chronver!`](crate::chronver) macro for labeled versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// const VERSION: Version = Version::parse_const("2020.01.06.3");
+    ///
+    /// assert_eq!(VERSION, Version::parse("2020.01.06.3").unwrap());
+    /// ```
+    ///
+    /// ```compile_fail
+    /// const VERSION: chronver::Version = chronver::Version::parse_const("2020.01.06-test");
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the string is not a valid version or contains a label. In a `const` context,
+    /// this fails compilation instead.
+    #[must_use]
+    pub const fn parse_const(version: &str) -> Self {
+        let parts = Parts::parse(version);
+        assert!(
+            parts.label.is_none(),
+            "labels are not supported in constant versions"
+        );
+
+        Self {
+            epoch: parts.epoch,
+            date: parts.date,
+            changeset: parts.changeset,
+            label: None,
+        }
+    }
+}
+
+/// Read the ASCII digits starting at the given position as number, returning the number and the
+/// position after the last digit.
+///
+/// # Panics
+///
+/// Panics if the number doesn't fit into a `u32`.
+const fn number(bytes: &[u8], mut pos: usize) -> (u32, usize) {
+    let mut value = 0_u32;
+    while pos < bytes.len() && bytes[pos].is_ascii_digit() {
+        value = match value.checked_mul(10) {
+            Some(value) => match value.checked_add((bytes[pos] - b'0') as u32) {
+                Some(value) => value,
+                None => panic!("number is too large"),
+            },
+            None => panic!("number is too large"),
+        };
+        pos += 1;
+    }
+    (value, pos)
+}
+
+/// Read two ASCII digits at the given position as number.
+///
+/// # Panics
+///
+/// Panics if either of the bytes is not a digit.
+const fn two_digits(bytes: &[u8], pos: usize) -> u8 {
+    assert!(
+        bytes[pos].is_ascii_digit() && bytes[pos + 1].is_ascii_digit(),
+        "date must only contain digits"
+    );
+    (bytes[pos] - b'0') * 10 + (bytes[pos + 1] - b'0')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_parser() {
+        for version in [
+            "2019.01.06",
+            "2019.01.06.1",
+            "2019.02.28-test",
+            "2020.02.29.4294967295-test.2",
+            "1!2019.01.06.1",
+        ] {
+            assert_eq!(
+                Version::parse(version).unwrap(),
+                Parts::parse(version).build(version)
+            );
+        }
+    }
+
+    #[test]
+    fn parse_const() {
+        const VERSION: Version = Version::parse_const("2019.01.06.2");
+        assert_eq!(Version::parse("2019.01.06.2").unwrap(), VERSION);
+        assert!(std::panic::catch_unwind(|| Version::parse_const("2019.01.06-a")).is_err());
+    }
+
+    #[test]
+    fn rejects_invalid() {
+        for version in [
+            "2019",
+            "2019-01-06",
+            "2019.13.06",
+            "2019.02.29",
+            "2019.01.0x",
+            "2019.01.06.",
+            "2019.01.06.4294967296",
+            "2019.01.06_1",
+            "4294967296!2019.01.06",
+        ] {
+            assert!(
+                std::panic::catch_unwind(|| Parts::parse(version)).is_err(),
+                "{version}"
+            );
+        }
+    }
+}
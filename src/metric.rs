@@ -0,0 +1,105 @@
+//! Numeric encoding of versions, to export the running version as metric.
+
+use std::convert::TryFrom;
+
+use crate::{Label, Version};
+
+/// Factor that shifts the date in front of the largest possible changeset.
+const DATE_FACTOR: u64 = 10_000_000_000;
+
+impl Version {
+    /// Encode this version as number, that can be exported as gauge and compared in alerts.
+    ///
+    /// The number is the date as `YYYYMMDD`, followed by the changeset padded to ten digits, so
+    /// it stays readable and increases with newer versions. The label is not included, use
+    /// [`metric_labels`](Self::metric_labels) to attach it to the metric. Dates before the year
+    /// 0 are all encoded as zero date.
+    ///
+    /// Versions of a later epoch sort after all versions of epoch 0, which the number can't
+    /// express, so `None` is returned for any epoch but 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.3-break").unwrap();
+    /// assert_eq!(version.as_metric_value(), Some(202001060000000003));
+    ///
+    /// let newer = Version::parse("2020.01.07").unwrap();
+    /// assert!(newer.as_metric_value() > version.as_metric_value());
+    ///
+    /// assert_eq!(Version::parse("1!2020.01.06").unwrap().as_metric_value(), None);
+    /// ```
+    #[must_use]
+    pub fn as_metric_value(&self) -> Option<u64> {
+        if self.epoch > 0 {
+            return None;
+        }
+
+        let date = u64::try_from(self.date.year()).map_or(0, |year| {
+            year * 10_000
+                + u64::from(u8::from(self.date.month())) * 100
+                + u64::from(self.date.day())
+        });
+
+        Some(date * DATE_FACTOR + u64::from(self.changeset))
+    }
+
+    /// Get labels to attach to the metric of [`as_metric_value`](Self::as_metric_value),
+    /// describing the part of the version that isn't encoded in the number. These are the
+    /// `class` (see [`ReleaseClass::as_str`]) and the `branch` of the label, which is empty for
+    /// versions without label.
+    ///
+    /// [`ReleaseClass::as_str`]: crate::ReleaseClass::as_str
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.3-login.2").unwrap();
+    /// assert_eq!(
+    ///     version.metric_labels(),
+    ///     [("class", "feature"), ("branch", "login")]
+    /// );
+    /// ```
+    #[must_use]
+    pub fn metric_labels(&self) -> [(&'static str, &str); 2] {
+        [
+            ("class", self.class().as_str()),
+            ("branch", self.label.as_ref().map_or("", Label::branch)),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn metric_value_is_monotonic() {
+        let negative = Version::from(time::macros::date!(-0001 - 12 - 31))
+            .as_metric_value()
+            .unwrap();
+        let values = [
+            "0000.01.01",
+            "2019.01.06",
+            "2019.01.06.4294967295",
+            "2019.01.07",
+            "2019.02.01",
+            "9999.12.31.4294967295",
+        ]
+        .iter()
+        .map(|v| Version::parse(v).unwrap().as_metric_value().unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(0, negative);
+        assert!(values.windows(2).all(|w| w[0] < w[1]), "{values:?}");
+        assert_eq!("999912314294967295", values[5].to_string());
+        assert_eq!(
+            None,
+            Version::parse("1!0000.01.01").unwrap().as_metric_value()
+        );
+    }
+}
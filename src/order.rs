@@ -0,0 +1,266 @@
+//! Adapters for ordering versions from newest to oldest, and alternative orderings.
+
+use std::cmp::Ordering;
+
+use crate::Version;
+
+/// Ways to order versions, to answer differently scoped questions like "what is the latest
+/// version" from the same collection.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{OrderingPolicy, Version};
+///
+/// let stable = Version::parse("2020.01.06").unwrap();
+/// let feature = Version::parse("2020.01.07-login").unwrap();
+///
+/// assert!(OrderingPolicy::Strict.compare(&feature, &stable).is_gt());
+/// assert!(OrderingPolicy::Channel.compare(&feature, &stable).is_lt());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum OrderingPolicy {
+    /// The regular ordering of versions, as defined by [`Ord`]. This is the default.
+    Strict,
+    /// Order by epoch, date and changeset only. Versions that only differ in their label are
+    /// considered equal.
+    IgnoreLabel,
+    /// Order by release channel first, where the more stable [`ReleaseClass`] is always greater,
+    /// and then by the regular ordering. This way, the latest version is taken from the most
+    /// stable channel that has any release.
+    ///
+    /// [`ReleaseClass`]: crate::ReleaseClass
+    Channel,
+}
+
+impl OrderingPolicy {
+    /// Compare two versions according to this policy.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    ///
+    /// use chronver::{OrderingPolicy, Version};
+    ///
+    /// let a = Version::parse("2020.01.06.1-alpha").unwrap();
+    /// let b = Version::parse("2020.01.06.1-beta").unwrap();
+    ///
+    /// assert_eq!(OrderingPolicy::Strict.compare(&a, &b), Ordering::Less);
+    /// assert_eq!(OrderingPolicy::IgnoreLabel.compare(&a, &b), Ordering::Equal);
+    /// ```
+    #[must_use]
+    pub fn compare(self, a: &Version, b: &Version) -> Ordering {
+        match self {
+            Self::Strict => a.cmp(b),
+            Self::IgnoreLabel => {
+                (a.epoch, a.date, a.changeset).cmp(&(b.epoch, b.date, b.changeset))
+            }
+            Self::Channel => b.class().cmp(&a.class()).then_with(|| a.cmp(b)),
+        }
+    }
+}
+
+impl Default for OrderingPolicy {
+    fn default() -> Self {
+        Self::Strict
+    }
+}
+
+/// Wrapper that reverses the ordering of the contained value, so the newest version is
+/// considered the smallest.
+///
+/// This works like [`std::cmp::Reverse`], but keeps version specific helpers at hand.
+///
+/// # Examples
+///
+/// ```
+/// use std::collections::BTreeSet;
+///
+/// use chronver::{Descending, Version};
+///
+/// let set = ["2020.01.06", "2020.01.07", "2020.01.06.1"]
+///     .iter()
+///     .map(|v| Descending(Version::parse(v).unwrap()))
+///     .collect::<BTreeSet<_>>();
+///
+/// let versions = set.iter().map(|v| v.0.to_string()).collect::<Vec<_>>();
+/// assert_eq!(versions, ["2020.01.07", "2020.01.06.1", "2020.01.06"]);
+/// ```
+#[derive(Debug, Clone, Copy, Default, Eq, PartialEq, Hash)]
+pub struct Descending<T>(pub T);
+
+impl<T> Descending<T> {
+    /// Unwrap the contained value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Descending, Version};
+    ///
+    /// let version = Version::parse("2020.01.06").unwrap();
+    /// assert_eq!(Descending(version.clone()).into_inner(), version);
+    /// ```
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T: Ord> Ord for Descending<T> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.cmp(&self.0)
+    }
+}
+
+impl<T: PartialOrd> PartialOrd for Descending<T> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        other.0.partial_cmp(&self.0)
+    }
+}
+
+impl Version {
+    /// Compare two versions from newest to oldest, meaning newer versions are ordered first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let mut versions = vec![
+    ///     Version::parse("2020.01.06").unwrap(),
+    ///     Version::parse("2020.01.07-beta").unwrap(),
+    ///     Version::parse("2020.01.07").unwrap(),
+    /// ];
+    /// versions.sort_by(Version::cmp_descending);
+    ///
+    /// let versions = versions.iter().map(ToString::to_string).collect::<Vec<_>>();
+    /// assert_eq!(versions, ["2020.01.07-beta", "2020.01.07", "2020.01.06"]);
+    /// ```
+    #[must_use]
+    pub fn cmp_descending(&self, other: &Self) -> Ordering {
+        other.cmp(self)
+    }
+
+    /// Get a sorting key for this version, that orders from newest to oldest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let versions = [
+    ///     Version::parse("2020.01.06").unwrap(),
+    ///     Version::parse("2020.01.07").unwrap(),
+    /// ];
+    ///
+    /// let newest = versions.iter().min_by_key(|v| v.descending_key()).unwrap();
+    /// assert_eq!(newest.to_string(), "2020.01.07");
+    /// ```
+    #[must_use]
+    pub const fn descending_key(&self) -> Descending<&Self> {
+        Descending(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BinaryHeap;
+
+    use super::*;
+    use crate::testing;
+
+    #[test]
+    fn policies_are_total_orders() {
+        let versions = [
+            "2019.01.06",
+            "2019.01.06-a",
+            "2019.01.06-break",
+            "2019.01.06.1",
+            "2019.01.06.1-b",
+            "2019.01.07-break",
+            "1!2019.01.01",
+        ]
+        .iter()
+        .map(|v| Version::parse(v).unwrap())
+        .collect::<Vec<_>>();
+
+        for policy in [
+            OrderingPolicy::Strict,
+            OrderingPolicy::IgnoreLabel,
+            OrderingPolicy::Channel,
+        ] {
+            /// Version that is ordered by the policy under test.
+            #[derive(Debug)]
+            struct ByPolicy<'a>(OrderingPolicy, &'a Version);
+
+            impl PartialEq for ByPolicy<'_> {
+                fn eq(&self, other: &Self) -> bool {
+                    self.cmp(other) == Ordering::Equal
+                }
+            }
+
+            impl Eq for ByPolicy<'_> {}
+
+            impl PartialOrd for ByPolicy<'_> {
+                fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+                    Some(self.cmp(other))
+                }
+            }
+
+            impl Ord for ByPolicy<'_> {
+                fn cmp(&self, other: &Self) -> Ordering {
+                    self.0.compare(self.1, other.1)
+                }
+            }
+
+            testing::check_order_laws(versions.iter().map(|v| ByPolicy(policy, v)));
+        }
+    }
+
+    #[test]
+    fn channel_prefers_stable() {
+        let v = |v: &str| Version::parse(v).unwrap();
+        let channel = OrderingPolicy::Channel;
+
+        assert_eq!(
+            Ordering::Greater,
+            channel.compare(&v("2019.01.06"), &v("2019.01.07-break"))
+        );
+        assert_eq!(
+            Ordering::Greater,
+            channel.compare(&v("2019.01.06-break"), &v("2019.01.07-a"))
+        );
+        assert_eq!(
+            Ordering::Less,
+            channel.compare(&v("2019.01.06-a"), &v("2019.01.07-a"))
+        );
+    }
+
+    #[test]
+    fn min_heap() {
+        let mut heap = [
+            "2019.01.06.1",
+            "2019.01.07",
+            "2019.01.06",
+            "2019.01.06-test",
+        ]
+        .iter()
+        .map(|v| Descending(Version::parse(v).unwrap()))
+        .collect::<BinaryHeap<_>>();
+
+        let mut order = Vec::new();
+        while let Some(Descending(version)) = heap.pop() {
+            order.push(version.to_string());
+        }
+
+        assert_eq!(
+            [
+                "2019.01.06",
+                "2019.01.06-test",
+                "2019.01.06.1",
+                "2019.01.07"
+            ],
+            order.as_slice()
+        );
+    }
+}
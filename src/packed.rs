@@ -0,0 +1,150 @@
+//! Packing of versions into a single integer, for use as database key and fast comparisons.
+//!
+//! From the most to the least significant bit, the layout is:
+//!
+//! | Field     | Bits | Description                           |
+//! | --------- | ---- | ------------------------------------- |
+//! | epoch     | 8    | Epoch of the version.                 |
+//! | year      | 15   | Year of the date, offset by `10000`.  |
+//! | month     | 4    | Month of the date, `1` to `12`.       |
+//! | day       | 5    | Day of the month.                     |
+//! | changeset | 32   | Changeset number.                     |
+
+use std::convert::TryFrom;
+
+use time::{Date, Month};
+
+use crate::{ChronVerError, Version};
+
+/// Offset that is added to the year, to keep negative years in order.
+const YEAR_OFFSET: i32 = 10_000;
+/// Largest epoch that fits into the packed form.
+const MAX_EPOCH: u32 = 0xff;
+/// Largest year (with offset) that fits into the packed form.
+const MAX_YEAR: u32 = 0x7fff;
+
+impl Version {
+    /// Pack the epoch, date and changeset of this version into a single integer, that orders
+    /// the same as the versions themselves. The label is not included and has to be stored
+    /// separately if needed.
+    ///
+    /// Returns `None` if the epoch is larger than 255, or the year is outside of `-9999` to
+    /// `9999`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.3").unwrap();
+    /// let packed = version.to_packed_u64().unwrap();
+    ///
+    /// assert_eq!(Version::from_packed_u64(packed).unwrap(), version);
+    /// assert!(Version::parse("2020.01.07").unwrap().to_packed_u64().unwrap() > packed);
+    /// ```
+    #[must_use]
+    pub fn to_packed_u64(&self) -> Option<u64> {
+        let year = u32::try_from(self.date.year() + YEAR_OFFSET).ok()?;
+        if self.epoch > MAX_EPOCH || year > MAX_YEAR {
+            return None;
+        }
+
+        Some(
+            u64::from(self.epoch) << 56
+                | u64::from(year) << 41
+                | u64::from(u8::from(self.date.month())) << 37
+                | u64::from(self.date.day()) << 32
+                | u64::from(self.changeset),
+        )
+    }
+
+    /// Unpack a version from the integer form of [`to_packed_u64`](Self::to_packed_u64). The
+    /// version has no label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("1!2020.01.06.3-beta").unwrap();
+    /// let unpacked = Version::from_packed_u64(version.to_packed_u64().unwrap()).unwrap();
+    ///
+    /// assert_eq!(unpacked.to_string(), "1!2020.01.06.3");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the integer doesn't describe a valid date.
+    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
+    pub fn from_packed_u64(packed: u64) -> Result<Self, ChronVerError> {
+        let year = ((packed >> 41) & u64::from(MAX_YEAR)) as i32 - YEAR_OFFSET;
+        let month = Month::try_from(((packed >> 37) & 0xf) as u8)?;
+        let day = ((packed >> 32) & 0x1f) as u8;
+
+        Ok(Self {
+            epoch: (packed >> 56) as u32,
+            date: Date::from_calendar_date(year, month, day)?,
+            changeset: packed as u32,
+            label: None,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn roundtrip_and_order() {
+        let versions = [
+            "0000.01.01",
+            "2019.01.06",
+            "2019.01.06.4294967295",
+            "2019.01.07",
+            "2019.02.01",
+            "9999.12.31.4294967295",
+            "1!0000.01.01",
+            "255!9999.12.31.4294967295",
+        ]
+        .iter()
+        .map(|v| Version::parse(v).unwrap())
+        .collect::<Vec<_>>();
+
+        let packed = versions
+            .iter()
+            .map(|v| v.to_packed_u64().unwrap())
+            .collect::<Vec<_>>();
+
+        assert!(packed.windows(2).all(|w| w[0] < w[1]), "{packed:?}");
+        for (version, packed) in versions.iter().zip(packed) {
+            assert_eq!(version, &Version::from_packed_u64(packed).unwrap());
+        }
+    }
+
+    #[test]
+    fn negative_years() {
+        let version = Version::from(time::macros::date!(-9999 - 01 - 01));
+        let packed = version.to_packed_u64().unwrap();
+
+        assert_eq!(version, Version::from_packed_u64(packed).unwrap());
+        assert!(
+            packed
+                < Version::parse("0000.01.01")
+                    .unwrap()
+                    .to_packed_u64()
+                    .unwrap()
+        );
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(
+            None,
+            Version::parse("256!2019.01.06").unwrap().to_packed_u64()
+        );
+        assert!(matches!(
+            Version::from_packed_u64(0),
+            Err(ChronVerError::InvalidComponents(_))
+        ));
+    }
+}
@@ -0,0 +1,1077 @@
+//! Reusable, configurable version parser.
+
+use std::{
+    collections::HashSet,
+    fmt,
+    hash::{Hash, Hasher},
+    sync::Arc,
+};
+
+use time::Date;
+
+use crate::{limits, ChronVerError, Clock, Version, DATE_FORMAT};
+
+/// Characters that are accepted in labels with [strict](ParseOptions::strict) parsing, set with
+/// [`ParseOptions::label_charset`].
+///
+/// # Examples
+///
+/// ```
+/// use chronver::LabelCharset;
+///
+/// assert!(LabelCharset::Ascii.allows('A'));
+/// assert!(!LabelCharset::Portable.allows('A'));
+/// assert!(LabelCharset::Unicode.allows('ü'));
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum LabelCharset {
+    /// ASCII alphanumerics, `.`, `_` and `-` (see [`limits::is_label_char`]).
+    Ascii,
+    /// Any alphanumerics, including non-ASCII ones, `.`, `_` and `-` (see
+    /// [`limits::is_unicode_label_char`]).
+    Unicode,
+    /// Lowercase ASCII alphanumerics, `.`, `_` and `-` (see
+    /// [`limits::is_portable_label_char`]), so versions round-trip through shells, URLs and
+    /// case-insensitive file systems.
+    Portable,
+}
+
+impl LabelCharset {
+    /// Check whether the character is part of this charset.
+    #[must_use]
+    pub fn allows(self, c: char) -> bool {
+        match self {
+            Self::Ascii => limits::is_label_char(c),
+            Self::Unicode => limits::is_unicode_label_char(c),
+            Self::Portable => limits::is_portable_label_char(c),
+        }
+    }
+}
+
+impl Default for LabelCharset {
+    fn default() -> Self {
+        Self::Ascii
+    }
+}
+
+/// Options that control how strictly versions are parsed.
+///
+/// The default options follow the chronologic versioning format exactly, as done by
+/// [`Version::parse`]. The options can be used once with [`Version::parse_with`], or
+/// repeatedly with a [`Parser`].
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{ParseOptions, Parser};
+///
+/// let parser = Parser::new(ParseOptions::new());
+/// assert!(parser.parse("2020.01.06").is_ok());
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ParseOptions {
+    /// Amount that is subtracted from parsed changesets.
+    pub(crate) changeset_offset: u32,
+    /// Branch names of labels that are accepted, or `None` to accept any label.
+    pub(crate) allowed_labels: Option<Vec<String>>,
+    /// Whether underscores are accepted in place of dots.
+    pub(crate) underscores: bool,
+    /// Whether labels are checked against the [`limits`](crate::limits).
+    pub(crate) strict: bool,
+    /// Whether unpadded and dash-separated dates and surrounding whitespace are accepted.
+    pub(crate) lenient: bool,
+    /// Whether a leading `v` or `V` is accepted, like in git tags.
+    pub(crate) v_prefix: bool,
+    /// Characters that are accepted in labels in strict mode.
+    pub(crate) label_charset: LabelCharset,
+    /// Whether the breaking label is recognized regardless of its case.
+    pub(crate) break_ignore_case: bool,
+    /// Clock for the latest date that is accepted, or `None` to accept any date.
+    clock: Option<SharedClock>,
+    /// Maximum length of the whole input in bytes, or `None` for no limit.
+    pub(crate) max_length: Option<usize>,
+    /// Maximum length of the label in bytes, or `None` for no limit.
+    pub(crate) max_label_length: Option<usize>,
+}
+
+/// A clock that can be shared between clones of [`ParseOptions`]. Two clocks are only equal if
+/// they are the same instance.
+#[derive(Clone)]
+struct SharedClock(Arc<dyn Clock + Send + Sync>);
+
+impl SharedClock {
+    /// Get the address of the clock, to compare and hash it by identity.
+    fn addr(&self) -> *const () {
+        Arc::as_ptr(&self.0).cast()
+    }
+}
+
+impl fmt::Debug for SharedClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("SharedClock(..)")
+    }
+}
+
+impl PartialEq for SharedClock {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self.addr(), other.addr())
+    }
+}
+
+impl Eq for SharedClock {}
+
+impl Hash for SharedClock {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.addr().hash(state);
+    }
+}
+
+impl ParseOptions {
+    /// Create the default options for strict parsing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::ParseOptions;
+    ///
+    /// assert_eq!(ParseOptions::new(), ParseOptions::default());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Subtract the given offset from parsed changesets, for projects that continue the counter
+    /// of a previous versioning scheme. Explicit changesets below the offset are rejected, while
+    /// versions without changeset keep the changeset 0.
+    ///
+    /// This is the counterpart to [`Version::with_changeset_offset`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().changeset_offset(47));
+    ///
+    /// assert_eq!(parser.parse("2024.04.03.47").unwrap().changeset, 0);
+    /// assert_eq!(parser.parse("2024.04.03.50").unwrap().changeset, 3);
+    /// assert!(parser.parse("2024.04.03.46").is_err());
+    /// assert_eq!(parser.parse("2024.04.03").unwrap().changeset, 0);
+    /// ```
+    #[must_use]
+    pub fn changeset_offset(self, offset: u32) -> Self {
+        Self {
+            changeset_offset: offset,
+            ..self
+        }
+    }
+
+    /// Accept underscores in place of dots between the date components and before the
+    /// changeset, like `2024_04_03_2`, for systems that don't allow dots in names. Underscores
+    /// in the label are kept as they are.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().underscores(true));
+    ///
+    /// assert_eq!(parser.parse("2024_04_03").unwrap().to_string(), "2024.04.03");
+    /// assert_eq!(
+    ///     parser.parse("2024.04.03_2-my_feature").unwrap().to_string(),
+    ///     "2024.04.03.2-my_feature"
+    /// );
+    /// ```
+    #[must_use]
+    pub fn underscores(self, enabled: bool) -> Self {
+        Self {
+            underscores: enabled,
+            ..self
+        }
+    }
+
+    /// Accept common near-ChronVer forms, as done by [`Version::parse_lenient`]: surrounding
+    /// whitespace, unpadded month and day components, like `2020.1.6`, and dashes between the
+    /// date components, like `2020-01-06`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().lenient(true));
+    ///
+    /// assert_eq!(parser.parse(" 2020-1-6.2 ").unwrap().to_string(), "2020.01.06.2");
+    /// ```
+    #[must_use]
+    pub fn lenient(self, enabled: bool) -> Self {
+        Self {
+            lenient: enabled,
+            ..self
+        }
+    }
+
+    /// Accept a leading `v` or `V` in front of the version, like in the git tag `v2020.01.06`,
+    /// as done by [`Version::parse_tag`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().v_prefix(true));
+    ///
+    /// assert_eq!(parser.parse("v2020.01.06").unwrap().to_string(), "2020.01.06");
+    /// assert_eq!(parser.parse("V2020.01.06").unwrap().to_string(), "2020.01.06");
+    /// assert!(parser.parse("2020.01.06").is_ok());
+    /// ```
+    #[must_use]
+    pub fn v_prefix(self, enabled: bool) -> Self {
+        Self {
+            v_prefix: enabled,
+            ..self
+        }
+    }
+
+    /// Enforce the [`limits`](crate::limits) on labels, rejecting labels that are too long or
+    /// contain characters outside of the [label charset](Self::label_charset), which is ASCII
+    /// alphanumerics, `.`, `_` and `-` by default.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().strict(true));
+    ///
+    /// assert!(parser.parse("2024.04.03-login_form.2").is_ok());
+    /// assert_eq!(
+    ///     parser.parse("2024.04.03-team/login"),
+    ///     Err(ChronVerError::InvalidLabelChar('/'))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn strict(self, enabled: bool) -> Self {
+        Self {
+            strict: enabled,
+            ..self
+        }
+    }
+
+    /// Reject inputs that are longer than the given amount of bytes with
+    /// [`ChronVerError::InputTooLong`], before doing any other work. This should be set when
+    /// parsing untrusted input, for example to [`limits::MAX_INPUT_LENGTH`].
+    ///
+    /// [`limits::MAX_INPUT_LENGTH`]: crate::limits::MAX_INPUT_LENGTH
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().max_length(12));
+    ///
+    /// assert!(parser.parse("2024.04.03.2").is_ok());
+    /// assert_eq!(parser.parse("2024.04.03.20"), Err(ChronVerError::InputTooLong));
+    /// ```
+    #[must_use]
+    pub fn max_length(self, length: usize) -> Self {
+        Self {
+            max_length: Some(length),
+            ..self
+        }
+    }
+
+    /// Reject labels that are longer than the given amount of bytes with
+    /// [`ChronVerError::LabelTooLong`]. If [strict](Self::strict) parsing is enabled as well,
+    /// the smaller of both limits applies.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().max_label_length(5));
+    ///
+    /// assert!(parser.parse("2024.04.03-login").is_ok());
+    /// assert_eq!(parser.parse("2024.04.03-login.2"), Err(ChronVerError::LabelTooLong));
+    /// ```
+    #[must_use]
+    pub fn max_label_length(self, length: usize) -> Self {
+        Self {
+            max_label_length: Some(length),
+            ..self
+        }
+    }
+
+    /// Select the characters that are accepted in labels, when [strict](Self::strict) parsing
+    /// is enabled. Other characters are rejected with [`ChronVerError::InvalidLabelChar`]. The
+    /// length limit is always counted in bytes.
+    ///
+    /// Without strict parsing, any label is accepted anyway.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, LabelCharset, ParseOptions, Parser};
+    ///
+    /// let strict = ParseOptions::new().strict(true);
+    /// let unicode = Parser::new(strict.clone().label_charset(LabelCharset::Unicode));
+    /// let portable = Parser::new(strict.label_charset(LabelCharset::Portable));
+    ///
+    /// assert!(unicode.parse("2024.04.03-größe").is_ok());
+    /// assert!(portable.parse("2024.04.03-login_form.2").is_ok());
+    /// assert_eq!(
+    ///     portable.parse("2024.04.03-Login"),
+    ///     Err(ChronVerError::InvalidLabelChar('L'))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn label_charset(self, charset: LabelCharset) -> Self {
+        Self {
+            label_charset: charset,
+            ..self
+        }
+    }
+
+    /// Recognize the label of breaking releases regardless of its case, like `-Break` or
+    /// `-BREAK`, and normalize it to lowercase. Otherwise, such labels are regular text labels
+    /// and the version is not [breaking](Version::is_breaking).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().break_ignore_case(true));
+    /// let version = parser.parse("2024.04.03-BREAK").unwrap();
+    ///
+    /// assert!(version.is_breaking());
+    /// assert_eq!(version.to_string(), "2024.04.03-break");
+    /// assert!(!Parser::default().parse("2024.04.03-BREAK").unwrap().is_breaking());
+    /// ```
+    #[must_use]
+    pub fn break_ignore_case(self, enabled: bool) -> Self {
+        Self {
+            break_ignore_case: enabled,
+            ..self
+        }
+    }
+
+    /// Reject versions that are dated after the current date of the given clock, with
+    /// [`ChronVerError::FutureDate`].
+    ///
+    /// The clock is asked for the current date on every parse, so options that are kept around
+    /// for a long time, like in a [`Parser`], follow the date as it changes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ParseOptions, Parser};
+    /// use time::macros::date;
+    ///
+    /// let parser = Parser::new(ParseOptions::new().deny_future(date!(2024-04-03)));
+    ///
+    /// assert!(parser.parse("2024.04.03.2").is_ok());
+    /// assert_eq!(
+    ///     parser.parse("2024.04.04"),
+    ///     Err(ChronVerError::FutureDate(date!(2024-04-04)))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn deny_future(self, clock: impl Clock + Send + Sync + 'static) -> Self {
+        Self {
+            clock: Some(SharedClock(Arc::new(clock))),
+            ..self
+        }
+    }
+
+    /// Get the latest date that is accepted right now, if [future dates](Self::deny_future)
+    /// are rejected.
+    pub(crate) fn latest_date(&self) -> Option<Date> {
+        self.clock.as_ref().map(|clock| clock.0.today())
+    }
+
+    /// Check the date against the [latest accepted date](Self::deny_future).
+    pub(crate) fn check_date(&self, date: Date) -> Result<(), ChronVerError> {
+        match self.latest_date() {
+            Some(latest) if date > latest => Err(ChronVerError::FutureDate(date)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Only accept labels whose branch name (see [`Label::branch`]) is one of the given names.
+    /// Versions without label are always accepted.
+    ///
+    /// [`Label::branch`]: crate::Label::branch
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().allowed_labels(["break", "hotfix"]));
+    ///
+    /// assert!(parser.parse("2024.04.03").is_ok());
+    /// assert!(parser.parse("2024.04.03-hotfix.2").is_ok());
+    /// assert_eq!(
+    ///     parser.parse("2024.04.03-beta"),
+    ///     Err(ChronVerError::LabelNotAllowed("beta".to_owned()))
+    /// );
+    /// ```
+    #[must_use]
+    pub fn allowed_labels<I>(self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        Self {
+            allowed_labels: Some(labels.into_iter().map(Into::into).collect()),
+            ..self
+        }
+    }
+}
+
+/// A version parser that holds on to its [`ParseOptions`], so they can be reused across many
+/// calls.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::Parser;
+///
+/// let parser = Parser::default();
+/// let versions = parser
+///     .parse_iter(["2020.01.06", "2020.01.07.1"])
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// assert_eq!(versions.len(), 2);
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct Parser {
+    /// Options that are applied to every parsed version.
+    options: ParseOptions,
+}
+
+impl Parser {
+    /// Create a new parser with the given options.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new());
+    /// assert_eq!(parser.options(), &ParseOptions::new());
+    /// ```
+    #[must_use]
+    pub const fn new(options: ParseOptions) -> Self {
+        Self { options }
+    }
+
+    /// Get the options of this parser.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// assert_eq!(Parser::default().options(), &ParseOptions::default());
+    /// ```
+    #[must_use]
+    pub const fn options(&self) -> &ParseOptions {
+        &self.options
+    }
+
+    /// Parse a string into a version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Parser, Version};
+    ///
+    /// assert_eq!(
+    ///     Parser::default().parse("2020.01.06.1-test"),
+    ///     Version::parse("2020.01.06.1-test")
+    /// );
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur.
+    pub fn parse(&self, version: &str) -> Result<Version, ChronVerError> {
+        Version::parse_with(version, &self.options)
+    }
+
+    /// Parse a string into a version like [`parse`](Self::parse), and report how the input
+    /// was corrected to get there. This allows to audit how many inputs only parse thanks to
+    /// [lenient](ParseOptions::lenient) parsing, [underscores](ParseOptions::underscores) or a
+    /// [`v` prefix](ParseOptions::v_prefix).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Correction, ParseOptions, Parser};
+    ///
+    /// let parser = Parser::new(ParseOptions::new().lenient(true).v_prefix(true));
+    ///
+    /// let (version, correction) = parser.parse_corrected("2020-1-6-test").unwrap();
+    /// assert_eq!(version.to_string(), "2020.01.06-test");
+    /// assert_eq!(
+    ///     correction,
+    ///     Some(Correction {
+    ///         original: "2020-1-6-test".to_owned(),
+    ///         canonical: "2020.01.06-test".to_owned(),
+    ///     })
+    /// );
+    ///
+    /// let (_, correction) = parser.parse_corrected("v2020.01.06-test").unwrap();
+    /// assert_eq!(correction.unwrap().canonical, "2020.01.06-test");
+    ///
+    /// let (_, correction) = parser.parse_corrected("2020.01.06-test").unwrap();
+    /// assert_eq!(correction, None);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur.
+    pub fn parse_corrected(
+        &self,
+        version: &str,
+    ) -> Result<(Version, Option<Correction>), ChronVerError> {
+        let parsed = self.parse(version)?;
+
+        let normalized = crate::normalize(version, &self.options);
+        let canonical = if self.options.v_prefix {
+            normalized
+                .strip_prefix(|c| c == 'v' || c == 'V')
+                .unwrap_or(&normalized)
+        } else {
+            &normalized
+        };
+
+        let correction = (canonical != version).then(|| Correction {
+            original: version.to_owned(),
+            canonical: canonical.to_owned(),
+        });
+
+        Ok((parsed, correction))
+    }
+
+    /// Parse raw bytes into a version, see [`Version::parse_bytes_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Parser;
+    ///
+    /// let version = Parser::default().parse_bytes(b"2020.01.06.1").unwrap();
+    /// assert_eq!(version.changeset, 1);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the bytes are not valid UTF-8. Otherwise, the same errors as for
+    /// [`Version::parse`] can occur.
+    pub fn parse_bytes(&self, version: &[u8]) -> Result<Version, ChronVerError> {
+        Version::parse_bytes_with(version, &self.options)
+    }
+
+    /// Parse raw bytes that contain only the date component of a version, like `2020.01.06`.
+    ///
+    /// This is useful for decoders that already split a version into its components.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Parser;
+    /// use time::macros::date;
+    ///
+    /// let parser = Parser::default();
+    ///
+    /// assert_eq!(parser.parse_date_bytes(b"2020.01.06"), Ok(date!(2020-01-06)));
+    /// assert!(parser.parse_date_bytes(b"2020.01.06.1").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the bytes are not valid UTF-8 or not exactly a valid date, or if
+    /// the date is in the future while [`ParseOptions::deny_future`] is enabled.
+    pub fn parse_date_bytes(&self, date: &[u8]) -> Result<Date, ChronVerError> {
+        let date = std::str::from_utf8(date)?;
+        let date = if self.options.underscores {
+            crate::normalize_underscores(date)
+        } else {
+            date.into()
+        };
+
+        let date = Date::parse(&date, &DATE_FORMAT)?;
+        self.options.check_date(date)?;
+        Ok(date)
+    }
+
+    /// Parse raw bytes that contain only the changeset component of a version, like `3`. The
+    /// [changeset offset](ParseOptions::changeset_offset) is applied to the result.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ParseOptions, Parser};
+    ///
+    /// assert_eq!(Parser::default().parse_changeset_bytes(b"3"), Ok(3));
+    ///
+    /// let parser = Parser::new(ParseOptions::new().changeset_offset(2));
+    /// assert_eq!(parser.parse_changeset_bytes(b"3"), Ok(1));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the bytes are not valid UTF-8, not a valid number or smaller
+    /// than the changeset offset.
+    pub fn parse_changeset_bytes(&self, changeset: &[u8]) -> Result<u32, ChronVerError> {
+        let changeset = std::str::from_utf8(changeset)?;
+        // Parsing an `u32` accepts a leading plus sign, which is not part of the version format.
+        let digits = if changeset.starts_with('+') {
+            ""
+        } else {
+            changeset
+        };
+
+        digits
+            .parse::<u32>()?
+            .checked_sub(self.options.changeset_offset)
+            .ok_or(ChronVerError::ChangesetBelowOffset)
+    }
+
+    /// Lazily parse each of the given strings into a version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Parser;
+    ///
+    /// let results = Parser::default()
+    ///     .parse_iter(vec!["2020.01.06".to_owned(), "invalid".to_owned()])
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert!(results[0].is_ok());
+    /// assert!(results[1].is_err());
+    /// ```
+    pub fn parse_iter<'a, I>(
+        &'a self,
+        versions: I,
+    ) -> impl Iterator<Item = Result<Version, ChronVerError>> + 'a
+    where
+        I: IntoIterator,
+        I::IntoIter: 'a,
+        I::Item: AsRef<str>,
+    {
+        versions.into_iter().map(move |v| self.parse(v.as_ref()))
+    }
+
+    /// Parse a whole batch of strings into versions, keeping the results in the same order as
+    /// the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Parser;
+    ///
+    /// let results = Parser::default().parse_all(["2020.01.06", "invalid", "2020.01.06"]);
+    ///
+    /// assert_eq!(results.len(), 3);
+    /// assert!(results[1].is_err());
+    /// ```
+    pub fn parse_all<I>(&self, versions: I) -> Vec<Result<Version, ChronVerError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        self.parse_iter(versions).collect()
+    }
+
+    /// Parse a whole batch of strings into versions like [`parse_all`](Self::parse_all), but
+    /// skip versions that are equal to an earlier one. Errors are always kept, so they can
+    /// still be reported.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Parser;
+    ///
+    /// let results = Parser::default().parse_all_unique([
+    ///     "2020.01.06",
+    ///     "2020.01.07",
+    ///     "2020.01.06.0",
+    ///     "invalid",
+    ///     "invalid",
+    /// ]);
+    ///
+    /// assert_eq!(results.len(), 4);
+    /// assert_eq!(results[1].as_ref().unwrap().to_string(), "2020.01.07");
+    /// assert!(results[2].is_err() && results[3].is_err());
+    /// ```
+    pub fn parse_all_unique<I>(&self, versions: I) -> Vec<Result<Version, ChronVerError>>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut seen = HashSet::new();
+
+        self.parse_iter(versions)
+            .filter(|result| {
+                result
+                    .as_ref()
+                    .map_or(true, |version| seen.insert(version.clone()))
+            })
+            .collect()
+    }
+}
+
+/// A correction that was applied to an input while parsing it, as reported by
+/// [`Parser::parse_corrected`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Correction {
+    /// The input as it was given.
+    pub original: String,
+    /// The input after normalizing it into the canonical format.
+    pub canonical: String,
+}
+
+/// Parse a whole batch of strings into versions with the default options, keeping the results
+/// in the same order as the input. This is a shorthand for [`Parser::parse_all`].
+///
+/// # Examples
+///
+/// ```
+/// let results = chronver::parse_all(vec!["2020.01.06".to_owned(), "2020.01.07.1".to_owned()]);
+///
+/// assert!(results.iter().all(Result::is_ok));
+/// ```
+pub fn parse_all<I>(versions: I) -> Vec<Result<Version, ChronVerError>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    Parser::default().parse_all(versions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Label;
+
+    #[test]
+    fn parse_bytes() {
+        let parser = Parser::default();
+        assert_eq!(
+            Version::parse("2019.01.06.1-test"),
+            parser.parse_bytes(b"2019.01.06.1-test")
+        );
+        assert!(matches!(
+            parser.parse_bytes(b"2019.01.06-\xff").unwrap_err(),
+            ChronVerError::InvalidUtf8(_)
+        ));
+        let parser = Parser::new(ParseOptions::new().strict(true).changeset_offset(1));
+        assert_eq!(
+            Err(ChronVerError::InvalidLabelChar(' ')),
+            parser.parse_bytes(b"2019.01.06.2-a b")
+        );
+        assert_eq!(
+            parser.parse("2019.01.06.2-a"),
+            parser.parse_bytes(b"2019.01.06.2-a")
+        );
+    }
+
+    #[test]
+    fn component_bytes() {
+        let parser = Parser::new(ParseOptions::new().underscores(true).changeset_offset(1));
+        let version = Version::parse("2019.01.06.4").unwrap();
+
+        assert_eq!(Ok(version.date), parser.parse_date_bytes(b"2019_01_06"));
+        assert_eq!(Ok(version.changeset), parser.parse_changeset_bytes(b"5"));
+        assert!(matches!(
+            parser.parse_changeset_bytes(b"+5"),
+            Err(ChronVerError::InvalidChangeset(_))
+        ));
+        assert_eq!(
+            Err(ChronVerError::ChangesetBelowOffset),
+            parser.parse_changeset_bytes(b"0")
+        );
+        assert!(matches!(
+            parser.parse_date_bytes(b"2019.01.06\xff"),
+            Err(ChronVerError::InvalidUtf8(_))
+        ));
+    }
+
+    #[test]
+    fn changeset_offset() {
+        let parser = Parser::new(ParseOptions::new().changeset_offset(10));
+        let version = Version::parse("2019.01.06.5-test").unwrap();
+
+        assert_eq!(
+            Ok(version.clone()),
+            parser.parse(&version.with_changeset_offset(10).to_string())
+        );
+        assert_eq!(
+            Err(ChronVerError::ChangesetBelowOffset),
+            parser.parse("2019.01.06.9")
+        );
+        assert_eq!(Version::parse("2019.01.06-a"), parser.parse("2019.01.06-a"));
+    }
+
+    #[test]
+    fn allowed_labels() {
+        let parser = Parser::new(ParseOptions::new().allowed_labels(["feature"]));
+
+        assert!(parser.parse("2019.01.06").is_ok());
+        assert!(parser.parse("2019.01.06-feature.3").is_ok());
+        assert_eq!(
+            Err(ChronVerError::LabelNotAllowed("break".to_owned())),
+            parser.parse("2019.01.06-break")
+        );
+    }
+
+    #[test]
+    fn parse_corrected() {
+        let parser = Parser::new(ParseOptions::new().lenient(true).underscores(true));
+        let corrected = |input| parser.parse_corrected(input).unwrap().1;
+
+        assert_eq!(None, corrected("2020.01.06.1-a_b"));
+        assert_eq!(
+            Some(Correction {
+                original: " 2020_1_6_1-a_b\n".to_owned(),
+                canonical: "2020.01.06.1-a_b".to_owned(),
+            }),
+            corrected(" 2020_1_6_1-a_b\n")
+        );
+        assert!(parser.parse_corrected("2020.13.06").is_err());
+
+        // Without the `v_prefix` option, the prefix is not a correction but an error.
+        assert!(parser.parse_corrected("v2020.01.06").is_err());
+    }
+
+    #[test]
+    fn underscores() {
+        let parser = Parser::new(ParseOptions::new().underscores(true));
+
+        assert_eq!(
+            Version::parse("2019.01.06.5-a_b"),
+            parser.parse("2019_01_06_5-a_b")
+        );
+        assert!(Parser::default().parse("2019_01_06").is_err());
+    }
+
+    #[test]
+    fn strict() {
+        let parser = Parser::new(ParseOptions::new().strict(true));
+        let label = "a".repeat(crate::limits::MAX_LABEL_LENGTH);
+
+        assert!(parser.parse(&format!("2019.01.06-{label}")).is_ok());
+        assert_eq!(
+            Err(ChronVerError::LabelTooLong),
+            parser.parse(&format!("2019.01.06-{label}a"))
+        );
+        assert_eq!(
+            Err(ChronVerError::InvalidLabelChar(' ')),
+            parser.parse("2019.01.06-a b")
+        );
+        assert!(Parser::default().parse("2019.01.06-a b").is_ok());
+    }
+
+    #[test]
+    fn break_ignore_case() {
+        let parser = Parser::new(
+            ParseOptions::new()
+                .break_ignore_case(true)
+                .allowed_labels(["break"]),
+        );
+        let expected = Version::parse("2019.01.06.1-break").unwrap();
+
+        for input in [
+            "2019.01.06.1-break",
+            "2019.01.06.1-Break",
+            "2019.01.06.1-BREAK",
+        ] {
+            assert_eq!(Ok(expected.clone()), parser.parse(input), "{input}");
+        }
+        assert_eq!(
+            Err(ChronVerError::LabelNotAllowed("BREAK".to_owned())),
+            parser.parse("2019.01.06.1-BREAK.2")
+        );
+        assert_eq!(
+            Err(ChronVerError::LabelNotAllowed("BREAKING".to_owned())),
+            parser.parse("2019.01.06.1-BREAKING")
+        );
+    }
+
+    #[test]
+    fn deny_future() {
+        let parser =
+            Parser::new(ParseOptions::new().deny_future(|| time::macros::date!(2019 - 12 - 31)));
+
+        assert!(parser.parse("2019.12.31.99-a").is_ok());
+        assert!(parser.parse("2019.01.06").is_ok());
+        assert_eq!(
+            Err(ChronVerError::FutureDate(time::macros::date!(
+                2020 - 01 - 01
+            ))),
+            parser.parse("2020.01.01")
+        );
+        assert!(Parser::default().parse("9999.12.31").is_ok());
+        assert_eq!(
+            Err(ChronVerError::FutureDate(time::macros::date!(
+                2020 - 01 - 01
+            ))),
+            parser.parse_date_bytes(b"2020.01.01")
+        );
+        assert!(parser.parse_date_bytes(b"2019.12.31").is_ok());
+    }
+
+    #[test]
+    fn deny_future_follows_the_clock() {
+        use std::sync::atomic::{AtomicBool, Ordering};
+
+        static NEXT_DAY: AtomicBool = AtomicBool::new(false);
+        let parser = Parser::new(ParseOptions::new().deny_future(|| {
+            if NEXT_DAY.load(Ordering::SeqCst) {
+                time::macros::date!(2020 - 01 - 01)
+            } else {
+                time::macros::date!(2019 - 12 - 31)
+            }
+        }));
+
+        assert!(parser.parse("2020.01.01").is_err());
+        NEXT_DAY.store(true, Ordering::SeqCst);
+        assert!(parser.parse("2020.01.01").is_ok());
+        assert!(parser.parse_date_bytes(b"2020.01.01").is_ok());
+    }
+
+    #[test]
+    fn portable() {
+        let parser = Parser::new(
+            ParseOptions::new()
+                .strict(true)
+                .label_charset(LabelCharset::Portable),
+        );
+
+        assert!(parser.parse("2019.01.06.1-a-b_c.0.9").is_ok());
+        for (input, c) in [
+            ("2019.01.06-a b", ' '),
+            ("2019.01.06-a+b", '+'),
+            ("2019.01.06-a\u{7}", '\u{7}'),
+            ("2019.01.06-aB", 'B'),
+            ("2019.01.06-ü", 'ü'),
+            ("2019.01.06-a/b.1", '/'),
+        ] {
+            assert_eq!(
+                Err(ChronVerError::InvalidLabelChar(c)),
+                parser.parse(input),
+                "{input:?}"
+            );
+        }
+        assert!(
+            Parser::new(ParseOptions::new().label_charset(LabelCharset::Portable))
+                .parse("2019.01.06-aB")
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn length_limits() {
+        let input = format!("2019.01.06-{}", "a".repeat(crate::limits::MAX_INPUT_LENGTH));
+        let parser = Parser::new(ParseOptions::new().max_length(crate::limits::MAX_INPUT_LENGTH));
+
+        assert!(parser
+            .parse(&input[..crate::limits::MAX_INPUT_LENGTH])
+            .is_ok());
+        assert_eq!(Err(ChronVerError::InputTooLong), parser.parse(&input));
+        assert_eq!(
+            Err(ChronVerError::InputTooLong),
+            parser.parse(&"x".repeat(10_000_000))
+        );
+        assert!(Parser::default().parse(&input).is_ok());
+
+        let parser = Parser::new(ParseOptions::new().max_label_length(200).strict(true));
+        assert_eq!(
+            Err(ChronVerError::LabelTooLong),
+            parser.parse(&format!(
+                "2019.01.06-{}",
+                "a".repeat(crate::limits::MAX_LABEL_LENGTH + 1)
+            ))
+        );
+        let parser = Parser::new(ParseOptions::new().max_label_length(0));
+        assert!(parser.parse("2019.01.06.1").is_ok());
+        assert!(parser.parse("2019.01.06.1-").is_ok());
+        assert_eq!(
+            Err(ChronVerError::LabelTooLong),
+            parser.parse("2019.01.06.1-a")
+        );
+    }
+
+    #[test]
+    fn unicode() {
+        let parser = Parser::new(
+            ParseOptions::new()
+                .strict(true)
+                .label_charset(LabelCharset::Unicode),
+        );
+
+        assert_eq!(
+            Some(Label::Feature {
+                branch: "ünïcødé_名前".to_owned(),
+                changeset: 2,
+            }),
+            parser.parse("2019.01.06-ünïcødé_名前.2").unwrap().label
+        );
+        assert_eq!(
+            Err(ChronVerError::InvalidLabelChar('\u{2003}')),
+            parser.parse("2019.01.06-a\u{2003}b")
+        );
+        assert_eq!(
+            Err(ChronVerError::LabelTooLong),
+            parser.parse(&format!(
+                "2019.01.06-{}",
+                "ü".repeat(crate::limits::MAX_LABEL_LENGTH)
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_all() {
+        let inputs = ["2019.01.06", "2019", "2019.01.06.0", "2019.01.06.1", "2019"];
+
+        let results = super::parse_all(inputs);
+        assert_eq!(inputs.len(), results.len());
+        for (input, result) in inputs.iter().zip(results) {
+            assert_eq!(Version::parse(input), result);
+        }
+
+        let results = Parser::default().parse_all_unique(inputs);
+        assert_eq!(
+            vec![
+                Version::parse("2019.01.06"),
+                Err(ChronVerError::TooShort),
+                Version::parse("2019.01.06.1"),
+                Err(ChronVerError::TooShort),
+            ],
+            results
+        );
+    }
+
+    #[test]
+    fn parse_iter() {
+        let results = Parser::default()
+            .parse_iter(&["2019.01.06", "2019", "2019.01.06.1"])
+            .collect::<Vec<_>>();
+
+        assert_eq!(3, results.len());
+        assert!(results[0].is_ok());
+        assert_eq!(Err(ChronVerError::TooShort), results[1]);
+        assert!(results[2].is_ok());
+    }
+}
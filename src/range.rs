@@ -0,0 +1,478 @@
+//! Ranges of versions with set operations.
+
+use std::{
+    cmp::{self, Ordering},
+    convert::TryFrom,
+    fmt::{self, Display},
+    ops::{
+        Bound, Range, RangeBounds, RangeFrom, RangeFull, RangeInclusive, RangeTo, RangeToInclusive,
+    },
+    str::FromStr,
+};
+
+use crate::{ChronVerError, Version};
+
+/// A range of versions between a lower and an upper bound, each of which can be inclusive,
+/// exclusive or unbounded.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{Version, VersionRange};
+///
+/// let parse = |v| Version::parse(v).unwrap();
+///
+/// let first = VersionRange::from(parse("2020.01.01")..parse("2020.03.01"));
+/// let second = VersionRange::from(parse("2020.02.01")..=parse("2020.04.01"));
+///
+/// let both = first.intersect(&second);
+/// assert!(both.contains(&parse("2020.02.15.3")));
+/// assert!(!both.contains(&parse("2020.03.01")));
+///
+/// let any = first.union(&second).unwrap();
+/// assert!(any.contains(&parse("2020.01.01")));
+/// assert!(any.contains(&parse("2020.04.01")));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct VersionRange {
+    /// The lower bound.
+    pub start: Bound<Version>,
+    /// The upper bound.
+    pub end: Bound<Version>,
+}
+
+impl VersionRange {
+    /// A range that contains all versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// assert!(VersionRange::FULL.contains(&Version::default()));
+    /// ```
+    pub const FULL: Self = Self {
+        start: Bound::Unbounded,
+        end: Bound::Unbounded,
+    };
+
+    /// Create a new range from the given bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Bound;
+    ///
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// let range = VersionRange::new(
+    ///     Bound::Excluded(Version::parse("2020.01.06").unwrap()),
+    ///     Bound::Unbounded,
+    /// );
+    /// assert!(range.contains(&Version::parse("2020.01.06.1").unwrap()));
+    /// ```
+    #[must_use]
+    pub const fn new(start: Bound<Version>, end: Bound<Version>) -> Self {
+        Self { start, end }
+    }
+
+    /// Parse a range literal in Rust syntax, like `2020.01.01..2020.03.01` (half-open),
+    /// `2020.01.01..=2020.03.01` (inclusive), `2020.01.01..`, `..2020.03.01`, `..=2020.03.01`
+    /// or `..` (all versions). An exclusive lower bound, which has no Rust syntax, is written with
+    /// `>` in front of the version, like `>2020.01.01..`.
+    ///
+    /// The range is split at the first `..`, so a label of the lower bound must not contain two
+    /// dots in a row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::ops::Bound;
+    ///
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// let range = VersionRange::parse("..=2020.12.31").unwrap();
+    ///
+    /// assert_eq!(range.start, Bound::Unbounded);
+    /// assert_eq!(range.end, Bound::Included(Version::parse("2020.12.31").unwrap()));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the literal doesn't contain `..`, an inclusive range has no upper
+    /// bound, or any of the versions is invalid (see [`Version::parse`]).
+    pub fn parse(range: &str) -> Result<Self, ChronVerError> {
+        let (start, end) = range
+            .trim()
+            .split_once("..")
+            .ok_or(ChronVerError::InvalidRange)?;
+
+        let start = match start.strip_prefix('>') {
+            Some("") => return Err(ChronVerError::InvalidRange),
+            Some(start) => Bound::Excluded(start.parse()?),
+            None if start.is_empty() => Bound::Unbounded,
+            None => Bound::Included(start.parse()?),
+        };
+        let end = match end.strip_prefix('=') {
+            Some("") => return Err(ChronVerError::InvalidRange),
+            Some(end) => Bound::Included(end.parse()?),
+            None if end.is_empty() => Bound::Unbounded,
+            None => Bound::Excluded(end.parse()?),
+        };
+
+        Ok(Self { start, end })
+    }
+
+    /// Check whether the version lies within this range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// let range = VersionRange::from(..Version::parse("2020.01.06").unwrap());
+    ///
+    /// assert!(range.contains(&Version::parse("2020.01.05.9").unwrap()));
+    /// assert!(!range.contains(&Version::parse("2020.01.06").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, version: &Version) -> bool {
+        RangeBounds::contains(self, version)
+    }
+
+    /// Check whether this range can't contain any version, because its lower bound lies above
+    /// its upper bound.
+    ///
+    /// Only the bounds are compared, so an exclusive range between two versions that have no
+    /// other version in between is not considered empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// let version = Version::parse("2020.01.06").unwrap();
+    ///
+    /// assert!(!VersionRange::from(version.clone()..=version.clone()).is_empty());
+    /// assert!(VersionRange::from(version.clone()..version).is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        match (&self.start, &self.end) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+            (Bound::Included(start), Bound::Included(end)) => start > end,
+            (Bound::Included(start) | Bound::Excluded(start), Bound::Excluded(end))
+            | (Bound::Excluded(start), Bound::Included(end)) => start >= end,
+        }
+    }
+
+    /// Create the range of versions that are contained in both ranges.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// let first = VersionRange::from(Version::parse("2020.01.06").unwrap()..);
+    /// let second = VersionRange::from(..Version::parse("2020.01.06").unwrap());
+    ///
+    /// assert!(first.intersect(&second).is_empty());
+    /// assert_eq!(first.intersect(&VersionRange::FULL), first);
+    /// ```
+    #[must_use]
+    pub fn intersect(&self, other: &Self) -> Self {
+        Self {
+            start: cmp::max_by(&self.start, &other.start, |a, b| cmp_start(a, b)).clone(),
+            end: cmp::min_by(&self.end, &other.end, |a, b| cmp_end(a, b)).clone(),
+        }
+    }
+
+    /// Create the range of versions that are contained in either range. If the ranges neither
+    /// overlap nor touch, the result can't be expressed as single range and `None` is returned
+    /// instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionRange};
+    ///
+    /// let parse = |v| Version::parse(v).unwrap();
+    ///
+    /// let first = VersionRange::from(parse("2020.01.01")..parse("2020.02.01"));
+    /// let second = VersionRange::from(parse("2020.02.01")..parse("2020.03.01"));
+    /// let third = VersionRange::from(parse("2020.04.01")..);
+    ///
+    /// assert_eq!(
+    ///     first.union(&second),
+    ///     Some(VersionRange::from(parse("2020.01.01")..parse("2020.03.01")))
+    /// );
+    /// assert_eq!(first.union(&third), None);
+    /// ```
+    #[must_use]
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        if self.is_empty() {
+            return Some(other.clone());
+        }
+        if other.is_empty() {
+            return Some(self.clone());
+        }
+
+        let (first, second) = if cmp_start(&self.start, &other.start) == Ordering::Greater {
+            (other, self)
+        } else {
+            (self, other)
+        };
+
+        let gap = match (&first.end, &second.start) {
+            (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+            (Bound::Excluded(end), Bound::Excluded(start)) => end <= start,
+            (Bound::Included(end) | Bound::Excluded(end), Bound::Included(start))
+            | (Bound::Included(end), Bound::Excluded(start)) => end < start,
+        };
+
+        (!gap).then(|| Self {
+            start: first.start.clone(),
+            end: cmp::max_by(&self.end, &other.end, |a, b| cmp_end(a, b)).clone(),
+        })
+    }
+}
+
+/// Order two lower bounds, from the least to the most restrictive.
+fn cmp_start(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Less,
+        (_, Bound::Unbounded) => Ordering::Greater,
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp(b)
+        }
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp(b).then(Ordering::Less),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp(b).then(Ordering::Greater),
+    }
+}
+
+/// Order two upper bounds, from the most to the least restrictive.
+fn cmp_end(a: &Bound<Version>, b: &Bound<Version>) -> Ordering {
+    match (a, b) {
+        (Bound::Unbounded, Bound::Unbounded) => Ordering::Equal,
+        (Bound::Unbounded, _) => Ordering::Greater,
+        (_, Bound::Unbounded) => Ordering::Less,
+        (Bound::Included(a), Bound::Included(b)) | (Bound::Excluded(a), Bound::Excluded(b)) => {
+            a.cmp(b)
+        }
+        (Bound::Included(a), Bound::Excluded(b)) => a.cmp(b).then(Ordering::Greater),
+        (Bound::Excluded(a), Bound::Included(b)) => a.cmp(b).then(Ordering::Less),
+    }
+}
+
+/// Renders the range in the same syntax that [`VersionRange::parse`] accepts, including the `>`
+/// in front of an exclusive lower bound.
+impl Display for VersionRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.start {
+            Bound::Included(start) => write!(f, "{start}..")?,
+            Bound::Excluded(start) => write!(f, ">{start}..")?,
+            Bound::Unbounded => f.write_str("..")?,
+        }
+        match &self.end {
+            Bound::Included(end) => write!(f, "={end}"),
+            Bound::Excluded(end) => write!(f, "{end}"),
+            Bound::Unbounded => Ok(()),
+        }
+    }
+}
+
+impl FromStr for VersionRange {
+    type Err = ChronVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for VersionRange {
+    type Error = ChronVerError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl RangeBounds<Version> for VersionRange {
+    fn start_bound(&self) -> Bound<&Version> {
+        match &self.start {
+            Bound::Included(v) => Bound::Included(v),
+            Bound::Excluded(v) => Bound::Excluded(v),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+
+    fn end_bound(&self) -> Bound<&Version> {
+        match &self.end {
+            Bound::Included(v) => Bound::Included(v),
+            Bound::Excluded(v) => Bound::Excluded(v),
+            Bound::Unbounded => Bound::Unbounded,
+        }
+    }
+}
+
+impl From<Range<Version>> for VersionRange {
+    fn from(range: Range<Version>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Excluded(range.end))
+    }
+}
+
+impl From<RangeInclusive<Version>> for VersionRange {
+    fn from(range: RangeInclusive<Version>) -> Self {
+        let (start, end) = range.into_inner();
+        Self::new(Bound::Included(start), Bound::Included(end))
+    }
+}
+
+impl From<RangeFrom<Version>> for VersionRange {
+    fn from(range: RangeFrom<Version>) -> Self {
+        Self::new(Bound::Included(range.start), Bound::Unbounded)
+    }
+}
+
+impl From<RangeTo<Version>> for VersionRange {
+    fn from(range: RangeTo<Version>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Excluded(range.end))
+    }
+}
+
+impl From<RangeToInclusive<Version>> for VersionRange {
+    fn from(range: RangeToInclusive<Version>) -> Self {
+        Self::new(Bound::Unbounded, Bound::Included(range.end))
+    }
+}
+
+impl From<RangeFull> for VersionRange {
+    fn from(_: RangeFull) -> Self {
+        Self::FULL
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn v(version: &str) -> Version {
+        Version::parse(version).unwrap()
+    }
+
+    #[test]
+    fn empty() {
+        let ex = |a, b| VersionRange::new(Bound::Excluded(v(a)), Bound::Excluded(v(b)));
+
+        assert!(ex("2019.01.06", "2019.01.06").is_empty());
+        assert!(!ex("2019.01.06", "2019.01.07").is_empty());
+        assert!(VersionRange::from(v("2019.01.07")..=v("2019.01.06")).is_empty());
+        assert!(!VersionRange::FULL.is_empty());
+    }
+
+    #[test]
+    fn intersect_prefers_exclusive_bounds() {
+        let inclusive = VersionRange::from(v("2019.01.06")..=v("2019.01.08"));
+        let exclusive = VersionRange::new(
+            Bound::Excluded(v("2019.01.06")),
+            Bound::Excluded(v("2019.01.08")),
+        );
+
+        assert_eq!(exclusive, inclusive.intersect(&exclusive));
+        assert_eq!(exclusive, exclusive.intersect(&inclusive));
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            Ok(VersionRange::from(v("2019.01.01")..v("2019.03.01"))),
+            " 2019.01.01..2019.03.01 ".parse()
+        );
+        assert_eq!(
+            Ok(VersionRange::from(v("2019.01.01-a")..=v("2019.03.01.2"))),
+            VersionRange::parse("2019.01.01-a..=2019.03.01.2")
+        );
+        assert_eq!(
+            Ok(VersionRange::from(v("2019.01.01")..)),
+            VersionRange::parse("2019.01.01..")
+        );
+        assert_eq!(
+            Ok(VersionRange::from(..v("2019.03.01"))),
+            VersionRange::parse("..2019.03.01")
+        );
+        assert_eq!(Ok(VersionRange::FULL), VersionRange::parse(".."));
+        assert_eq!(
+            Ok(VersionRange::new(
+                Bound::Excluded(v("2019.01.01")),
+                Bound::Excluded(v("2019.03.01"))
+            )),
+            VersionRange::parse(">2019.01.01..2019.03.01")
+        );
+
+        assert_eq!(
+            Err(ChronVerError::InvalidRange),
+            VersionRange::parse("2019.01.01")
+        );
+        assert_eq!(
+            Err(ChronVerError::InvalidRange),
+            VersionRange::parse("2019.01.01..=")
+        );
+        assert_eq!(
+            Err(ChronVerError::InvalidRange),
+            VersionRange::parse(">..2019.01.01")
+        );
+        assert_eq!(
+            Err(ChronVerError::TooShort),
+            VersionRange::parse("2019..2020")
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for range in [
+            "..",
+            "2019.01.01..",
+            "..=2019.01.01",
+            "2019.01.01-a..2019.02.01.1",
+        ] {
+            assert_eq!(range, VersionRange::parse(range).unwrap().to_string());
+        }
+
+        let bounds = |version: &str| {
+            [
+                Bound::Included(v(version)),
+                Bound::Excluded(v(version)),
+                Bound::Unbounded,
+            ]
+        };
+
+        for start in bounds("2019.01.01-a") {
+            for end in bounds("2019.02.01.1") {
+                let range = VersionRange::new(start.clone(), end);
+                assert_eq!(Ok(range.clone()), range.to_string().parse(), "{range}");
+            }
+        }
+    }
+
+    #[test]
+    fn union() {
+        let left = VersionRange::from(..v("2019.01.06"));
+        let right = VersionRange::new(Bound::Excluded(v("2019.01.06")), Bound::Unbounded);
+        let point = VersionRange::from(v("2019.01.06")..=v("2019.01.06"));
+
+        assert_eq!(None, left.union(&right));
+        assert_eq!(
+            Some(VersionRange::from(..=v("2019.01.06"))),
+            left.union(&point)
+        );
+        assert_eq!(
+            Some(VersionRange::FULL),
+            left.union(&point).unwrap().union(&right)
+        );
+        assert_eq!(
+            Some(right.clone()),
+            right.union(&VersionRange::from(v("2019.01.07")..v("2019.01.06")))
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Grouped summaries of a list of releases, for example to include in release notes.
+
+use std::{
+    borrow::Borrow,
+    collections::BTreeMap,
+    fmt::{self, Display, Write},
+};
+
+use time::Month;
+
+use crate::Version;
+
+/// Output format of a release report.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Format {
+    /// Plain text, with indented versions below each month.
+    Text,
+    /// Markdown, with a heading for each month and versions as list items.
+    Markdown,
+}
+
+/// Render the given versions into a report, grouped by month and ordered from newest to
+/// oldest. Breaking releases and releases with a feature label are flagged as such.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{report, Version};
+///
+/// let versions = ["2020.01.06", "2020.01.07-break", "2020.02.01.1-login.2"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// assert_eq!(
+///     report::render(&versions, report::Format::Text),
+///     "February 2020\n  \
+///        2020.02.01.1-login.2 (feature login)\n\
+///      \n\
+///      January 2020\n  \
+///        2020.01.07-break (breaking)\n  \
+///        2020.01.06\n"
+/// );
+/// ```
+pub fn render<I>(versions: I, format: Format) -> String
+where
+    I: IntoIterator,
+    I::Item: Borrow<Version>,
+{
+    let mut months = BTreeMap::<(i32, Month), Vec<_>>::new();
+    for version in versions {
+        let version = version.borrow();
+        months
+            .entry((version.date.year(), version.date.month()))
+            .or_default()
+            .push(version.clone());
+    }
+
+    Report { months, format }.to_string()
+}
+
+/// Releases grouped by month, that can be rendered in the given format.
+struct Report {
+    /// Versions of each month.
+    months: BTreeMap<(i32, Month), Vec<Version>>,
+    /// Format of the output.
+    format: Format,
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, ((year, month), versions)) in self.months.iter().rev().enumerate() {
+            if i > 0 {
+                f.write_char('\n')?;
+            }
+
+            match self.format {
+                Format::Text => writeln!(f, "{month} {year}")?,
+                Format::Markdown => writeln!(f, "## {month} {year}\n")?,
+            }
+
+            let mut versions = versions.iter().collect::<Vec<_>>();
+            versions.sort_by(|a, b| a.cmp_descending(b));
+
+            for version in versions {
+                match self.format {
+                    Format::Text => write!(f, "  {version}")?,
+                    Format::Markdown => write!(f, "- `{version}`")?,
+                }
+
+                if let Some(flag) = flag(version, self.format) {
+                    write!(f, " {flag}")?;
+                }
+
+                f.write_char('\n')?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Describe the kind of release for the version, if it is a breaking or feature release.
+fn flag(version: &Version, format: Format) -> Option<String> {
+    if version.is_breaking() {
+        return Some(match format {
+            Format::Text => "(breaking)".to_owned(),
+            Format::Markdown => "**breaking**".to_owned(),
+        });
+    }
+
+    let branch = version.label.as_ref()?.branch();
+
+    Some(match format {
+        Format::Text => format!("(feature {branch})"),
+        Format::Markdown => format!("*feature `{branch}`*"),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn versions() -> Vec<Version> {
+        [
+            "2019.12.31",
+            "2020.01.06",
+            "2020.01.06.1-break",
+            "2020.01.07-beta",
+        ]
+        .iter()
+        .map(|v| Version::parse(v).unwrap())
+        .collect()
+    }
+
+    #[test]
+    fn markdown() {
+        assert_eq!(
+            "## January 2020\n\
+             \n\
+             - `2020.01.07-beta` *feature `beta`*\n\
+             - `2020.01.06.1-break` **breaking**\n\
+             - `2020.01.06`\n\
+             \n\
+             ## December 2019\n\
+             \n\
+             - `2019.12.31`\n",
+            render(versions(), Format::Markdown)
+        );
+    }
+
+    #[test]
+    fn empty() {
+        assert_eq!("", render(Vec::<Version>::new(), Format::Text));
+    }
+}
@@ -0,0 +1,434 @@
+//! Version requirements, to match versions against constraints like `>=2020.01.06`.
+
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    str::FromStr,
+};
+
+use time::{Date, Month};
+
+use crate::{split_epoch, ChronVerError, Version};
+
+/// Operator of a single [`Comparator`].
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Op {
+    /// `=`, the version must be exactly the same, including the label.
+    Exact,
+    /// `>`, the version must be newer.
+    Greater,
+    /// `>=`, the version must be the same or newer.
+    GreaterEq,
+    /// `<`, the version must be older.
+    Less,
+    /// `<=`, the version must be the same or older.
+    LessEq,
+    /// `~`, the version must be the same or newer, but from the same month.
+    Tilde,
+    /// `^`, the version must be the same or newer, but from the same year.
+    Caret,
+    /// `YYYY.*`, the version must be from the same year. Only the epoch and year of the
+    /// comparator's version are relevant.
+    YearWildcard,
+    /// `YYYY.MM.*`, the version must be from the same month. Only the epoch, year and month of
+    /// the comparator's version are relevant.
+    MonthWildcard,
+}
+
+impl Op {
+    /// All operators with their textual form, longer ones first so they are matched before
+    /// their prefixes.
+    const SYMBOLS: [(Self, &'static str); 7] = [
+        (Self::GreaterEq, ">="),
+        (Self::LessEq, "<="),
+        (Self::Greater, ">"),
+        (Self::Less, "<"),
+        (Self::Exact, "="),
+        (Self::Tilde, "~"),
+        (Self::Caret, "^"),
+    ];
+
+    /// Get the textual form of this operator, which is empty for wildcards.
+    const fn symbol(self) -> &'static str {
+        match self {
+            Self::YearWildcard | Self::MonthWildcard => "",
+            Self::Exact => "=",
+            Self::Greater => ">",
+            Self::GreaterEq => ">=",
+            Self::Less => "<",
+            Self::LessEq => "<=",
+            Self::Tilde => "~",
+            Self::Caret => "^",
+        }
+    }
+}
+
+/// A single constraint of a [`VersionReq`], like `>=2020.01.06`.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{Comparator, Op, Version};
+///
+/// let comparator = "<2021.01.01".parse::<Comparator>().unwrap();
+///
+/// assert_eq!(comparator.op, Op::Less);
+/// assert!(comparator.matches(&Version::parse("2020.12.31.4").unwrap()));
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Comparator {
+    /// How the version is compared.
+    pub op: Op,
+    /// The version that is compared against.
+    pub version: Version,
+}
+
+impl Comparator {
+    /// Parse a single comparator. Without an operator, the version must match exactly.
+    ///
+    /// Instead of an operator and version, a wildcard pattern like `2020.*` or `2020.01.*` can
+    /// be given, to match every version of that year or month.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Comparator, Op};
+    ///
+    /// assert_eq!(Comparator::parse(">=2020.01.06").unwrap().op, Op::GreaterEq);
+    /// assert_eq!(Comparator::parse("2020.01.06").unwrap().op, Op::Exact);
+    /// assert_eq!(Comparator::parse("~2020.01.06").unwrap().op, Op::Tilde);
+    /// assert_eq!(Comparator::parse("2020.01.*").unwrap().op, Op::MonthWildcard);
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the comparator is empty, or the version after the operator is
+    /// invalid (see [`Version::parse`]). Wildcard patterns must consist of a four digit year
+    /// and optionally a valid two digit month.
+    pub fn parse(comparator: &str) -> Result<Self, ChronVerError> {
+        let comparator = comparator.trim();
+        if let Some(pattern) = comparator.strip_suffix(".*") {
+            return Self::parse_wildcard(pattern);
+        }
+        let (op, version) = Op::SYMBOLS
+            .iter()
+            .find_map(|(op, symbol)| comparator.strip_prefix(symbol).map(|rem| (*op, rem)))
+            .unwrap_or((Op::Exact, comparator));
+
+        let version = version.trim_start();
+        if version.is_empty() {
+            return Err(ChronVerError::InvalidRequirement);
+        }
+
+        Ok(Self {
+            op,
+            version: version.parse()?,
+        })
+    }
+
+    /// Check whether the version is from the same epoch and year as the comparator's version.
+    const fn same_year(&self, version: &Version) -> bool {
+        version.epoch == self.version.epoch && version.date.year() == self.version.date.year()
+    }
+
+    /// Check whether the version is from the same epoch and month as the comparator's version.
+    fn same_month(&self, version: &Version) -> bool {
+        self.same_year(version) && version.date.month() == self.version.date.month()
+    }
+
+    /// Parse the part of a wildcard pattern in front of the `.*`, being either `YYYY` or
+    /// `YYYY.MM`, with an optional epoch prefix.
+    fn parse_wildcard(pattern: &str) -> Result<Self, ChronVerError> {
+        /// Parse a fixed-length number, rejecting signs and whitespace.
+        fn number<T: FromStr>(value: &str, len: usize) -> Result<T, ChronVerError> {
+            if value.len() != len || !value.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ChronVerError::InvalidRequirement);
+            }
+            value.parse().map_err(|_| ChronVerError::InvalidRequirement)
+        }
+
+        let (epoch, pattern) = split_epoch(pattern)?;
+        let (year, month) = match pattern.split_once('.') {
+            Some((year, month)) => (year, Some(month)),
+            None => (pattern, None),
+        };
+
+        let year = number(year, 4)?;
+        let (op, month) = match month {
+            Some(month) => (Op::MonthWildcard, Month::try_from(number::<u8>(month, 2)?)?),
+            None => (Op::YearWildcard, Month::January),
+        };
+
+        Ok(Self {
+            op,
+            version: Version {
+                epoch,
+                ..Date::from_calendar_date(year, month, 1)?.into()
+            },
+        })
+    }
+
+    /// Check whether the given version satisfies this comparator.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Comparator, Version};
+    ///
+    /// let comparator = Comparator::parse(">2020.01.06").unwrap();
+    ///
+    /// assert!(comparator.matches(&Version::parse("2020.01.06.1").unwrap()));
+    /// assert!(!comparator.matches(&Version::parse("2020.01.06").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        match self.op {
+            Op::Exact => version == &self.version,
+            Op::Greater => version > &self.version,
+            Op::GreaterEq => version >= &self.version,
+            Op::Less => version < &self.version,
+            Op::LessEq => version <= &self.version,
+            Op::Tilde => version >= &self.version && self.same_month(version),
+            Op::Caret => version >= &self.version && self.same_year(version),
+            Op::YearWildcard => self.same_year(version),
+            Op::MonthWildcard => self.same_month(version),
+        }
+    }
+}
+
+impl Display for Comparator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if matches!(self.op, Op::YearWildcard | Op::MonthWildcard) && self.version.epoch > 0 {
+            write!(f, "{}!", self.version.epoch)?;
+        }
+        match self.op {
+            Op::YearWildcard => write!(f, "{:04}.*", self.version.date.year()),
+            Op::MonthWildcard => write!(
+                f,
+                "{:04}.{:02}.*",
+                self.version.date.year(),
+                u8::from(self.version.date.month())
+            ),
+            _ => write!(f, "{}{}", self.op.symbol(), self.version),
+        }
+    }
+}
+
+impl FromStr for Comparator {
+    type Err = ChronVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+/// A set of comparators that a version must all satisfy, like `>=2020.01.06, <2021.01.01`.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{Version, VersionReq};
+///
+/// let req = VersionReq::parse(">=2020.01.06, <2021.01.01").unwrap();
+///
+/// assert!(req.matches(&Version::parse("2020.06.15.2-break").unwrap()));
+/// assert!(!req.matches(&Version::parse("2021.01.01").unwrap()));
+/// assert_eq!(req.to_string(), ">=2020.01.06, <2021.01.01");
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct VersionReq {
+    /// The comparators that must all match.
+    pub comparators: Vec<Comparator>,
+}
+
+impl VersionReq {
+    /// A requirement without any comparators, matching every version. Written as `*`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionReq};
+    ///
+    /// assert!(VersionReq::STAR.matches(&Version::default()));
+    /// assert_eq!(VersionReq::parse("*").unwrap(), VersionReq::STAR);
+    /// ```
+    pub const STAR: Self = Self {
+        comparators: Vec::new(),
+    };
+
+    /// Parse a comma-separated list of comparators (see [`Comparator::parse`]), or `*` to match
+    /// any version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::VersionReq;
+    ///
+    /// assert_eq!(VersionReq::parse("<2021.01.01").unwrap().comparators.len(), 1);
+    /// assert_eq!(VersionReq::parse(">2020.01.06, <=2020.02.01").unwrap().comparators.len(), 2);
+    /// assert!(VersionReq::parse(">2020.01.06,").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the requirement is empty or any of the comparators is invalid.
+    pub fn parse(req: &str) -> Result<Self, ChronVerError> {
+        if req.trim() == "*" {
+            return Ok(Self::STAR);
+        }
+
+        Ok(Self {
+            comparators: req
+                .split(',')
+                .map(Comparator::parse)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+
+    /// Check whether the given version satisfies all comparators of this requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionReq};
+    ///
+    /// let req = VersionReq::parse(">=2020.01.06").unwrap();
+    ///
+    /// assert!(req.matches(&Version::parse("2020.01.06").unwrap()));
+    /// assert!(!req.matches(&Version::parse("2020.01.05.3").unwrap()));
+    /// ```
+    #[must_use]
+    pub fn matches(&self, version: &Version) -> bool {
+        self.comparators.iter().all(|c| c.matches(version))
+    }
+}
+
+impl Display for VersionReq {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.comparators.is_empty() {
+            return f.write_str("*");
+        }
+
+        for (i, comparator) in self.comparators.iter().enumerate() {
+            if i > 0 {
+                f.write_str(", ")?;
+            }
+            comparator.fmt(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for VersionReq {
+    type Err = ChronVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for VersionReq {
+    type Error = ChronVerError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn matches(req: &str, version: &str) -> bool {
+        VersionReq::parse(req)
+            .unwrap()
+            .matches(&Version::parse(version).unwrap())
+    }
+
+    #[test]
+    fn operators() {
+        assert!(matches("2019.01.06-a", "2019.01.06-a"));
+        assert!(!matches("=2019.01.06", "2019.01.06-a"));
+        assert!(matches(">2019.01.06", "2019.01.06-a"));
+        assert!(!matches(">2019.01.06", "2019.01.06"));
+        assert!(matches(">=2019.01.06", "2019.01.06"));
+        assert!(matches("<2019.01.06.1", "2019.01.06-a"));
+        assert!(!matches("<2019.01.06", "2019.01.06"));
+        assert!(matches("<=2019.01.06", "2019.01.06"));
+    }
+
+    #[test]
+    fn tilde_and_caret() {
+        assert!(matches("~2019.01.06", "2019.01.06"));
+        assert!(matches("~2019.01.06", "2019.01.31.3"));
+        assert!(!matches("~2019.01.06", "2019.01.05"));
+        assert!(!matches("~2019.01.06", "2019.02.01"));
+
+        assert!(matches("^2019.01.06.2", "2019.01.06.2"));
+        assert!(matches("^2019.01.06", "2019.12.31"));
+        assert!(!matches("^2019.01.06.2", "2019.01.06.1"));
+        assert!(!matches("^2019.01.06", "2020.01.01"));
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(matches("2019.*", "2019.12.31.2-a"));
+        assert!(!matches("2019.*", "2020.01.01"));
+        assert!(matches("2019.02.*", "2019.02.28"));
+        assert!(!matches("2019.02.*", "2019.03.01"));
+        assert!(!matches("2019.02.*", "2018.02.01"));
+        assert!(matches("2019.*, >=2019.06.01", "2019.06.01"));
+        assert!(!matches("2019.*", "1!2019.06.01"));
+        assert!(matches("1!2019.02.*", "1!2019.02.28"));
+        assert_eq!(
+            "1!2019.02.*",
+            VersionReq::parse("1!2019.02.*").unwrap().to_string()
+        );
+
+        for invalid in [".*", "19.*", "+201.*", "2019.1.*", "2019.01.01.*"] {
+            assert_eq!(
+                Err(ChronVerError::InvalidRequirement),
+                VersionReq::parse(invalid),
+                "{invalid}"
+            );
+        }
+        assert!(matches!(
+            VersionReq::parse("2019.13.*"),
+            Err(ChronVerError::InvalidComponents(_))
+        ));
+    }
+
+    #[test]
+    fn whitespace() {
+        let req = VersionReq::parse(" >= 2019.01.06 ,< 2019.02.01 ").unwrap();
+        assert_eq!(">=2019.01.06, <2019.02.01", req.to_string());
+    }
+
+    #[test]
+    fn invalid() {
+        assert_eq!(
+            Err(ChronVerError::InvalidRequirement),
+            VersionReq::parse("")
+        );
+        assert_eq!(
+            Err(ChronVerError::InvalidRequirement),
+            VersionReq::parse(">=")
+        );
+        assert_eq!(Err(ChronVerError::TooShort), VersionReq::parse(">2019"));
+        assert!(VersionReq::parse("~>2019.01.06").is_err());
+    }
+
+    #[test]
+    fn roundtrip() {
+        for req in [
+            "*",
+            "=2019.01.06.1-a",
+            ">2019.01.06, <=2019.12.31",
+            "2019.*, 2019.02.*",
+            "~2019.01.06, ^2019.01.06-a",
+        ] {
+            assert_eq!(req, VersionReq::parse(req).unwrap().to_string());
+        }
+    }
+}
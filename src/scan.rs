@@ -0,0 +1,300 @@
+//! Locating versions that are embedded in larger strings, like file names or changelogs.
+
+use std::{ffi::OsStr, iter::FusedIterator, ops::Range};
+
+use crate::Version;
+
+/// A version that was found in a file name, together with the text around it.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::Version;
+///
+/// let found = Version::from_file_name("backup-2020.01.06.2.tar.gz").unwrap();
+///
+/// assert_eq!(found.prefix, "backup-");
+/// assert_eq!(found.version, Version::parse("2020.01.06.2").unwrap());
+/// assert_eq!(found.suffix, ".tar.gz");
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct FileNameVersion<'a> {
+    /// The part of the file name in front of the version.
+    pub prefix: &'a str,
+    /// The version itself.
+    pub version: Version,
+    /// The part of the file name after the version.
+    pub suffix: &'a str,
+}
+
+impl Version {
+    /// Find and parse the first version that is embedded in a file name, like
+    /// `backup-2020.01.06.2.tar.gz`, returning it together with the surrounding prefix and
+    /// suffix. The file name can be given as [`str`], [`OsStr`] or [`Path`](std::path::Path),
+    /// but must not contain any directories.
+    ///
+    /// A version must not be directly preceded or followed by a digit. Labels are limited to
+    /// the same characters as in [`Version::parse_prefix`], and end in front of the first dot
+    /// that is not followed by a numeric segment, as that starts the file extension. For
+    /// example, `db-2020.01.06-rc.2.tar.gz` has the label `rc.2` and the suffix `.tar.gz`.
+    ///
+    /// Returns `None` if the name contains no version, or is not valid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::path::Path;
+    ///
+    /// use chronver::Version;
+    ///
+    /// let path = Path::new("/var/backups/db_2020.01.06-nightly.tar.gz");
+    /// let found = Version::from_file_name(path.file_name().unwrap()).unwrap();
+    ///
+    /// assert_eq!(found.prefix, "db_");
+    /// assert_eq!(found.version.to_string(), "2020.01.06-nightly");
+    /// assert_eq!(found.suffix, ".tar.gz");
+    ///
+    /// assert!(Version::from_file_name("backup-latest.tar.gz").is_none());
+    /// assert!(Version::from_file_name("backup-12020.01.06.tar.gz").is_none());
+    /// ```
+    #[must_use]
+    pub fn from_file_name<N>(name: &N) -> Option<FileNameVersion<'_>>
+    where
+        N: AsRef<OsStr> + ?Sized,
+    {
+        let name = name.as_ref().to_str()?;
+        let (mut span, mut version) = find_version(name, 0, u8::is_ascii_digit)?;
+
+        // Labels can contain dots, but in file names they more likely start the extension.
+        if let Some(end) = extension_start(&name[span.clone()]) {
+            span.end = span.start + end;
+            version = Self::parse(&name[span.clone()]).ok()?;
+        }
+
+        Some(FileNameVersion {
+            prefix: &name[..span.start],
+            version,
+            suffix: &name[span.end..],
+        })
+    }
+
+    /// Find the first version in arbitrary text, like a changelog or commit message, and return
+    /// its byte range together with the parsed version.
+    ///
+    /// Versions must stand as a whole word, so they must not be directly preceded or followed
+    /// by an ASCII alphanumeric character or underscore. For example, nothing is found in
+    /// `12020.01.061`. Labels are limited to the same characters as in
+    /// [`Version::parse_prefix`], and a dot at the end is treated as punctuation instead of being
+    /// part of the label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let text = "Fixed in 2020.01.06.2-hotfix.";
+    /// let (span, version) = Version::find_in(text).unwrap();
+    ///
+    /// assert_eq!(&text[span], "2020.01.06.2-hotfix");
+    /// assert_eq!(version.to_string(), "2020.01.06.2-hotfix");
+    ///
+    /// assert!(Version::find_in("build 12020.01.061").is_none());
+    /// assert!(Version::find_in("v2020.01.06").is_none());
+    /// ```
+    #[must_use]
+    pub fn find_in(text: &str) -> Option<(Range<usize>, Self)> {
+        find_version(text, 0, is_word)
+    }
+
+    /// Iterate over all versions in arbitrary text, like a changelog or release notes, in the
+    /// order they appear. Each version is found with the same rules as in
+    /// [`find_in`](Self::find_in), and yielded together with its byte range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let notes = "## 2020.01.07\nReverts 2020.01.06.1, which broke 2020.01.06.";
+    /// let versions = Version::find_all(notes)
+    ///     .map(|(_, version)| version.to_string())
+    ///     .collect::<Vec<_>>();
+    ///
+    /// assert_eq!(versions, ["2020.01.07", "2020.01.06.1", "2020.01.06"]);
+    /// ```
+    pub const fn find_all(text: &str) -> FindVersions<'_> {
+        FindVersions { text, pos: 0 }
+    }
+}
+
+/// Iterator over all versions in a text, created by [`Version::find_all`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct FindVersions<'a> {
+    /// The text that is searched.
+    text: &'a str,
+    /// Byte position in the text, from where to search for the next version.
+    pos: usize,
+}
+
+impl Iterator for FindVersions<'_> {
+    type Item = (Range<usize>, Version);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let found = find_version(self.text, self.pos, is_word);
+        self.pos = found.as_ref().map_or(self.text.len(), |(span, _)| span.end);
+        found
+    }
+}
+
+impl FusedIterator for FindVersions<'_> {}
+
+/// Check whether the byte is part of a word, like `\w` in regular expressions.
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn is_word(b: &u8) -> bool {
+    b.is_ascii_alphanumeric() || *b == b'_'
+}
+
+/// Find the start of a file extension in the label of a version, which is the first dot in the
+/// label that is not followed by a numeric segment. Returns its byte position in the version.
+fn extension_start(version: &str) -> Option<usize> {
+    let label = version.find('-')? + 1;
+
+    version[label..]
+        .match_indices('.')
+        .map(|(i, _)| label + i)
+        .find(|&i| {
+            let segment = version[i + 1..].split('.').next().unwrap_or_default();
+            segment.is_empty() || !segment.bytes().all(|b| b.is_ascii_digit())
+        })
+}
+
+/// Find the first version in the text that starts at or after the given byte position, and is
+/// not directly preceded or followed by a byte matching the predicate. Returns its byte range
+/// together with the parsed version.
+fn find_version(
+    text: &str,
+    from: usize,
+    is_word: fn(&u8) -> bool,
+) -> Option<(Range<usize>, Version)> {
+    let bytes = text.as_bytes();
+
+    (from..bytes.len())
+        .filter(|&i| bytes[i].is_ascii_digit() && (i == 0 || !is_word(&bytes[i - 1])))
+        .find_map(|start| {
+            let (version, rest) = Version::parse_prefix(&text[start..]).ok()?;
+            if rest.bytes().next().as_ref().map_or(false, is_word) {
+                return None;
+            }
+
+            let end = text.len() - rest.len();
+            // A dot at the end of a label is more likely the end of a sentence.
+            match text[start..end].strip_suffix('.') {
+                Some(trimmed) if version.label.is_some() => {
+                    let version = Version::parse(trimmed).ok()?;
+                    Some((start..end - 1, version))
+                }
+                _ => Some((start..end, version)),
+            }
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use super::*;
+
+    #[test]
+    fn file_names() {
+        let parts = |name: &str| {
+            Version::from_file_name(name)
+                .map(|f| format!("{}|{}|{}", f.prefix, f.version, f.suffix))
+        };
+
+        for (name, expected) in [
+            ("2019.01.06", "|2019.01.06|"),
+            ("app-1.2-1!2019.01.06.3.zip", "app-1.2-|1!2019.01.06.3|.zip"),
+            ("backup_7_2019.01.06-full", "backup_7_|2019.01.06-full|"),
+            (
+                "x-2019.13.06-2019.01.06.log",
+                "x-2019.13.06-|2019.01.06|.log",
+            ),
+            ("ünï-2019.01.06-é.txt", "ünï-|2019.01.06|-é.txt"),
+            (
+                "db-2020.01.06-nightly.tar.gz",
+                "db-|2020.01.06-nightly|.tar.gz",
+            ),
+            ("db-2020.01.06-rc.2.tar.gz", "db-|2020.01.06-rc.2|.tar.gz"),
+            ("db-2020.01.06.3-a.b.2", "db-|2020.01.06.3-a|.b.2"),
+            ("db-2020.01.06-a.1.2.log", "db-|2020.01.06-a.1.2|.log"),
+        ] {
+            assert_eq!(Some(expected.to_owned()), parts(name), "{name:?}");
+        }
+        for name in ["2019.01.061", "2019.01.6", ""] {
+            assert_eq!(None, parts(name), "{name:?}");
+        }
+
+        let found = Version::from_file_name(Path::new("v2019.01.06.tar")).unwrap();
+        assert_eq!("v", found.prefix);
+    }
+
+    #[test]
+    fn find_in() {
+        let find = |text: &str| Version::find_in(text).map(|(span, v)| (span, v.to_string()));
+
+        for (text, span, version) in [
+            ("2019.01.06", 0..10, "2019.01.06"),
+            ("Released 2019.01.06.", 9..19, "2019.01.06"),
+            ("(2019.01.06.2-a.1)", 1..17, "2019.01.06.2-a.1"),
+            ("release 2019.01.06-beta.", 8..23, "2019.01.06-beta"),
+            ("see 2019.01.06-a.b.2: done", 4..20, "2019.01.06-a.b.2"),
+            ("12019.01.06 2019.01.07", 12..22, "2019.01.07"),
+            (
+                "x2019.01.06 2019.13.06, 1!2019.01.08",
+                24..36,
+                "1!2019.01.08",
+            ),
+            ("ü2019.01.06ü", 2..12, "2019.01.06"),
+        ] {
+            assert_eq!(Some((span, version.to_owned())), find(text), "{text:?}");
+        }
+
+        for text in ["", "12020.01.061", "2019.01.06x", "_2019.01.06"] {
+            assert_eq!(None, find(text), "{text:?}");
+        }
+    }
+
+    #[test]
+    fn find_all() {
+        let text = "2019.01.06 2019.01.06.1,2019.01.06-a.\n12019.01.06 x2019.01.07 (1!2019.01.08)";
+        let found = Version::find_all(text)
+            .map(|(span, v)| {
+                assert_eq!(&text[span], v.to_string());
+                v.to_string()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            ["2019.01.06", "2019.01.06.1", "2019.01.06-a", "1!2019.01.08"],
+            found.as_slice()
+        );
+
+        let mut iter = Version::find_all("2019.01.06");
+        assert!(iter.next().is_some());
+        assert!(iter.next().is_none());
+        assert!(iter.next().is_none());
+        assert_eq!(0, Version::find_all("").count());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn non_utf8() {
+        use std::os::unix::ffi::OsStrExt;
+
+        assert_eq!(
+            None,
+            Version::from_file_name(OsStr::from_bytes(b"\xff2019.01.06"))
+        );
+    }
+}
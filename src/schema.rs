@@ -0,0 +1,178 @@
+//! Machine-readable description of the version grammar that this crate accepts.
+//!
+//! Bindings and validators in other languages can use [`SCHEMA`] as source of truth, for
+//! example to generate test cases at the boundaries of each component.
+
+use crate::{limits, BREAK_LABEL};
+
+/// A numeric component of a version.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Component {
+    /// Name of the component.
+    pub name: &'static str,
+    /// Separator in front of the component, if any.
+    pub prefix: Option<char>,
+    /// Separator after the component, if any.
+    pub suffix: Option<char>,
+    /// Whether the component can be left out.
+    pub optional: bool,
+    /// Exact amount of digits, or `None` if any amount is allowed.
+    pub digits: Option<usize>,
+    /// Smallest allowed value.
+    pub min: u32,
+    /// Largest allowed value. Days are further limited by the length of their month.
+    pub max: u32,
+}
+
+/// Rules for the optional label at the end of a version.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct LabelRules {
+    /// Separator between the rest of the version and the label.
+    pub prefix: char,
+    /// Separator in front of the changeset of a feature label, like the `.` in `login.2`. The
+    /// last separator is used, if the text after it is a valid changeset.
+    pub feature_separator: char,
+    /// The label that marks a release with breaking changes.
+    pub break_label: &'static str,
+    /// Maximum length in bytes in strict mode (see [`limits::MAX_LABEL_LENGTH`]).
+    pub strict_max_length: usize,
+    /// Characters that are allowed in strict mode, besides ASCII alphanumerics (see
+    /// [`limits::LABEL_SPECIAL_CHARS`]).
+    pub strict_special_chars: &'static [char],
+}
+
+/// Description of the whole version grammar.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct Schema {
+    /// The numeric components, in the order they appear in a version.
+    pub components: &'static [Component],
+    /// Rules for the label.
+    pub label: LabelRules,
+    /// Regular expression that matches the general structure of a version, without checking
+    /// value ranges. The capture groups are the components, followed by the label.
+    pub pattern: &'static str,
+}
+
+/// The version grammar of this crate, as accepted by [`Version::parse`](crate::Version::parse)
+/// with default options.
+///
+/// The same grammar applies to the [`chronver!`](crate::chronver) macro and the serde
+/// implementations. The length of the input is not limited, unless set with
+/// [`ParseOptions::max_length`](crate::ParseOptions::max_length).
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{schema::SCHEMA, Version};
+///
+/// let changeset = SCHEMA
+///     .components
+///     .iter()
+///     .find(|c| c.name == "changeset")
+///     .unwrap();
+///
+/// assert!(Version::parse(&format!("2020.01.06.{}", changeset.max)).is_ok());
+/// assert!(Version::parse(&format!("2020.01.06.{}", u64::from(changeset.max) + 1)).is_err());
+/// ```
+pub const SCHEMA: Schema = Schema {
+    components: &[
+        Component {
+            name: "epoch",
+            prefix: None,
+            suffix: Some('!'),
+            optional: true,
+            digits: None,
+            min: 0,
+            max: u32::MAX,
+        },
+        Component {
+            name: "year",
+            prefix: None,
+            suffix: None,
+            optional: false,
+            digits: Some(4),
+            min: 0,
+            max: 9999,
+        },
+        Component {
+            name: "month",
+            prefix: Some('.'),
+            suffix: None,
+            optional: false,
+            digits: Some(2),
+            min: 1,
+            max: 12,
+        },
+        Component {
+            name: "day",
+            prefix: Some('.'),
+            suffix: None,
+            optional: false,
+            digits: Some(2),
+            min: 1,
+            max: 31,
+        },
+        Component {
+            name: "changeset",
+            prefix: Some('.'),
+            suffix: None,
+            optional: true,
+            digits: None,
+            min: 0,
+            max: u32::MAX,
+        },
+    ],
+    label: LabelRules {
+        prefix: '-',
+        feature_separator: '.',
+        break_label: BREAK_LABEL,
+        strict_max_length: limits::MAX_LABEL_LENGTH,
+        strict_special_chars: &limits::LABEL_SPECIAL_CHARS,
+    },
+    pattern: r"^(?:([0-9]+)!)?([0-9]{4})\.([0-9]{2})\.([0-9]{2})(?:\.([0-9]+))?(?:-(.*))?$",
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ChronVerError, Version};
+
+    #[test]
+    fn matches_parser() {
+        let component = |name| SCHEMA.components.iter().find(|c| c.name == name).unwrap();
+
+        let epoch = component("epoch");
+        assert!(Version::parse(&format!("{}!2019.01.06", epoch.max)).is_ok());
+        assert!(Version::parse(&format!("{}!2019.01.06", u64::from(epoch.max) + 1)).is_err());
+
+        let year = component("year");
+        assert!(Version::parse(&format!("{:04}.01.06", year.min)).is_ok());
+        assert!(Version::parse(&format!("{:04}.01.06", year.max)).is_ok());
+
+        let month = component("month");
+        assert!(Version::parse(&format!("2019.{:02}.06", month.min - 1)).is_err());
+        assert!(Version::parse(&format!("2019.{:02}.06", month.max + 1)).is_err());
+
+        let day = component("day");
+        assert!(Version::parse(&format!("2019.01.{:02}", day.max)).is_ok());
+        assert!(Version::parse(&format!("2019.01.{:02}", day.max + 1)).is_err());
+
+        assert_eq!(
+            Some(Version::parse("2019.01.06-break").unwrap()),
+            Version::parse(&format!(
+                "2019.01.06{}{}",
+                SCHEMA.label.prefix, SCHEMA.label.break_label
+            ))
+            .ok()
+            .filter(Version::is_breaking)
+        );
+        assert!(matches!(
+            Version::parse("2019.01.06-a").unwrap().label,
+            Some(crate::Label::Text(_))
+        ));
+        assert_eq!(
+            Err(ChronVerError::InvalidLabel),
+            Version::parse("2019.01.06+a")
+        );
+    }
+}
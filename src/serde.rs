@@ -0,0 +1,688 @@
+//! Serialization of versions and labels in their string form, with [`serde`](::serde).
+//!
+//! In human-readable formats, versions can also be deserialized from an object with separate
+//! fields for each component, like the one created by `Version::to_json`. Other formats, like
+//! bincode, postcard or CBOR, use a compact tuple of the epoch, year, month, day, changeset and
+//! the optional label instead. Data in these formats that was written in the string form by
+//! earlier versions can no longer be read this way, but only with the [`string`] module.
+//!
+//! The modules in here can be used with `#[serde(with = "...")]` on single fields, to pick a
+//! specific representation.
+
+use std::{
+    fmt::{self, Display},
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use serde::{
+    de::{self, value::MapAccessDeserializer, MapAccess, SeqAccess, Visitor},
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+use time::{Date, Month};
+
+use crate::{Label, StableVersion, Version};
+
+/// Visitor that parses any string, borrowed or owned, with the [`FromStr`] implementation of
+/// the value.
+struct FromStrVisitor<T> {
+    /// Description of the expected string.
+    expecting: &'static str,
+    /// Marker for the parsed type.
+    marker: PhantomData<T>,
+}
+
+impl<T> FromStrVisitor<T> {
+    /// Create a visitor, that describes the expected string with the given text.
+    const fn new(expecting: &'static str) -> Self {
+        Self {
+            expecting,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<T> Visitor<'_> for FromStrVisitor<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.expecting)
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+}
+
+/// The object form of a version, with separate fields for each component.
+#[derive(Deserialize)]
+struct Detailed {
+    /// Epoch of the version.
+    #[serde(default)]
+    epoch: u32,
+    /// Year of the date.
+    year: i32,
+    /// Month of the date, `1` to `12`.
+    month: u8,
+    /// Day of the month.
+    day: u8,
+    /// Changeset number.
+    #[serde(default)]
+    changeset: u32,
+    /// Label text.
+    #[serde(default)]
+    label: Option<String>,
+}
+
+impl Detailed {
+    /// Convert into a version, failing if the date is invalid.
+    fn into_version<E: de::Error>(self) -> Result<Version, E> {
+        let date = Month::try_from(self.month)
+            .and_then(|month| Date::from_calendar_date(self.year, month, self.day))
+            .map_err(E::custom)?;
+
+        Ok(Version {
+            epoch: self.epoch,
+            date,
+            changeset: self.changeset,
+            label: self.label.as_deref().map(Label::parse),
+        })
+    }
+}
+
+/// Visitor for versions, that accepts the string and the object form.
+struct VersionVisitor;
+
+impl<'de> Visitor<'de> for VersionVisitor {
+    type Value = Version;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a version string or object")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        Detailed::deserialize(MapAccessDeserializer::new(map))?.into_version()
+    }
+}
+
+/// Compact form of a version for formats that are not human-readable, as tuple of epoch, year,
+/// month, day, changeset and label.
+#[derive(Serialize)]
+struct CompactRef<'a>(u32, i32, u8, u8, u32, Option<&'a Label>);
+
+impl<'a> From<&'a Version> for CompactRef<'a> {
+    fn from(version: &'a Version) -> Self {
+        Self(
+            version.epoch,
+            version.date.year(),
+            version.date.month().into(),
+            version.date.day(),
+            version.changeset,
+            version.label.as_ref(),
+        )
+    }
+}
+
+/// Owned counterpart of [`CompactRef`] for deserialization.
+struct Compact(u32, i32, u8, u8, u32, Option<Label>);
+
+impl Compact {
+    /// Convert into a version, failing if the date is invalid.
+    fn into_version<E: de::Error>(self) -> Result<Version, E> {
+        let Self(epoch, year, month, day, changeset, label) = self;
+        let date = Month::try_from(month)
+            .and_then(|month| Date::from_calendar_date(year, month, day))
+            .map_err(E::custom)?;
+
+        Ok(Version {
+            epoch,
+            date,
+            changeset,
+            label,
+        })
+    }
+}
+
+/// Visitor for versions in formats that are not human-readable. It accepts the compact tuple,
+/// and the string form that was used for all formats before.
+struct CompactVisitor;
+
+impl<'de> Visitor<'de> for CompactVisitor {
+    type Value = Version;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a compact version tuple or version string")
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        v.parse().map_err(E::custom)
+    }
+
+    fn visit_bytes<E: de::Error>(self, v: &[u8]) -> Result<Self::Value, E> {
+        Version::parse_bytes(v).map_err(E::custom)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let compact = Compact(
+            next_element(&mut seq, 0, &self)?,
+            next_element(&mut seq, 1, &self)?,
+            next_element(&mut seq, 2, &self)?,
+            next_element(&mut seq, 3, &self)?,
+            next_element(&mut seq, 4, &self)?,
+            next_element(&mut seq, 5, &self)?,
+        );
+        compact.into_version()
+    }
+}
+
+/// Get the next element of a sequence, failing with an invalid length error if there is none.
+fn next_element<'de, A, T>(
+    seq: &mut A,
+    index: usize,
+    expected: &dyn de::Expected,
+) -> Result<T, A::Error>
+where
+    A: SeqAccess<'de>,
+    T: Deserialize<'de>,
+{
+    seq.next_element()?
+        .ok_or_else(|| de::Error::invalid_length(index, expected))
+}
+
+impl Serialize for Version {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            CompactRef::from(self).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Version {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(VersionVisitor)
+        } else {
+            deserializer.deserialize_tuple(6, CompactVisitor)
+        }
+    }
+}
+
+impl Serialize for StableVersion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        Version::serialize(self, serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for StableVersion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Self::try_from(Version::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl Serialize for Label {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Label {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a label string"))
+    }
+}
+
+pub mod string {
+    //! Always use the canonical string form of a [`Version`], like `"2020.01.06.1-break"`,
+    //! regardless of the data format.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! use chronver::Version;
+    //! use serde::{Deserialize, Serialize};
+    //!
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Release {
+    //!     #[serde(with = "chronver::serde::string")]
+    //!     version: Version,
+    //! }
+    //!
+    //! let release = Release {
+    //!     version: Version::parse("2020.01.06.1-break").unwrap(),
+    //! };
+    //! let json = serde_json::to_string(&release).unwrap();
+    //! assert_eq!(json, r#"{"version":"2020.01.06.1-break"}"#);
+    //!
+    //! let release = serde_json::from_str::<Release>(&json).unwrap();
+    //! assert_eq!(release.version.to_string(), "2020.01.06.1-break");
+    //! ```
+
+    use serde::{Deserializer, Serializer};
+
+    use super::FromStrVisitor;
+    use crate::Version;
+
+    /// Serialize the version as string.
+    ///
+    /// # Errors
+    ///
+    /// Forwards any error of the serializer.
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(version)
+    }
+
+    /// Deserialize a version from a string.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input is not a string or not a valid version.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        deserializer.deserialize_str(FromStrVisitor::new("a version string"))
+    }
+}
+
+pub mod option_string {
+    //! Like [`string`](super::string), but for optional versions, which are `null` (or the
+    //! equivalent of the data format) if missing.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! use chronver::Version;
+    //! use serde::{Deserialize, Serialize};
+    //!
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Release {
+    //!     #[serde(with = "chronver::serde::option_string")]
+    //!     previous: Option<Version>,
+    //! }
+    //!
+    //! let release = Release { previous: None };
+    //! assert_eq!(serde_json::to_string(&release).unwrap(), r#"{"previous":null}"#);
+    //!
+    //! let release = serde_json::from_str::<Release>(r#"{"previous":"2020.01.06"}"#).unwrap();
+    //! assert_eq!(release.previous.unwrap().to_string(), "2020.01.06");
+    //! ```
+
+    use std::fmt;
+
+    use serde::{de::Visitor, Deserializer, Serialize, Serializer};
+
+    use crate::Version;
+
+    /// Wrapper to serialize the inner version with [`string`](super::string).
+    struct AsString<'a>(&'a Version);
+
+    impl Serialize for AsString<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::string::serialize(self.0, serializer)
+        }
+    }
+
+    /// Visitor for optional versions in string form.
+    struct OptionVisitor;
+
+    impl<'de> Visitor<'de> for OptionVisitor {
+        type Value = Option<Version>;
+
+        fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+            f.write_str("an optional version string")
+        }
+
+        fn visit_none<E>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_unit<E>(self) -> Result<Self::Value, E> {
+            Ok(None)
+        }
+
+        fn visit_some<D: Deserializer<'de>>(self, d: D) -> Result<Self::Value, D::Error> {
+            super::string::deserialize(d).map(Some)
+        }
+    }
+
+    /// Serialize the optional version as string.
+    ///
+    /// # Errors
+    ///
+    /// Forwards any error of the serializer.
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S: Serializer>(
+        version: &Option<Version>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match version {
+            Some(version) => serializer.serialize_some(&AsString(version)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    /// Deserialize an optional version from a string.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input is neither missing nor a valid version string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Version>, D::Error> {
+        deserializer.deserialize_option(OptionVisitor)
+    }
+}
+
+pub mod packed {
+    //! Use the integer form of [`Version::to_packed_u64`], which is compact and sorts like the
+    //! versions. Only versions without label can be serialized this way.
+    //!
+    //! # Examples
+    //!
+    //! ```
+    //! use chronver::Version;
+    //! use serde::{Deserialize, Serialize};
+    //!
+    //! #[derive(Serialize, Deserialize)]
+    //! struct Release {
+    //!     #[serde(with = "chronver::serde::packed")]
+    //!     version: Version,
+    //! }
+    //!
+    //! let release = Release {
+    //!     version: Version::parse("2020.01.06.1").unwrap(),
+    //! };
+    //! let json = serde_json::to_string(&release).unwrap();
+    //! assert_eq!(json, r#"{"version":26432422740492289}"#);
+    //!
+    //! let release = serde_json::from_str::<Release>(&json).unwrap();
+    //! assert_eq!(release.version.to_string(), "2020.01.06.1");
+    //! ```
+
+    use serde::{de, ser, Deserialize, Deserializer, Serializer};
+
+    use crate::Version;
+
+    /// Serialize the version as packed integer.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the version has a label or can't be packed (see
+    /// [`Version::to_packed_u64`]), or the serializer fails.
+    pub fn serialize<S: Serializer>(version: &Version, serializer: S) -> Result<S::Ok, S::Error> {
+        if version.label.is_some() {
+            return Err(ser::Error::custom("versions with label can't be packed"));
+        }
+
+        let packed = version
+            .to_packed_u64()
+            .ok_or_else(|| ser::Error::custom("version is out of range for packing"))?;
+        serializer.serialize_u64(packed)
+    }
+
+    /// Deserialize a version from its packed integer form.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the input is not an integer or not a valid packed version.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Version, D::Error> {
+        Version::from_packed_u64(u64::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn owned_strings() {
+        let version = Version::parse("2019.01.06.1-test.2").unwrap();
+        let json = br#""2019.01.06.1-test.2""#;
+
+        assert_eq!(
+            version,
+            serde_json::from_reader::<_, Version>(&json[..]).unwrap()
+        );
+        assert_eq!(
+            version.label,
+            Some(serde_json::from_reader::<_, Label>(&br#""test.2""#[..]).unwrap())
+        );
+        assert_eq!(
+            "2019.01.06.1",
+            serde_json::from_reader::<_, StableVersion>(&br#""2019.01.06.1""#[..])
+                .unwrap()
+                .to_string()
+        );
+        // Escaped strings can't be borrowed from the input either.
+        assert_eq!(
+            Version::parse("2019.01.06-a\"b").unwrap(),
+            serde_json::from_str::<Version>(r#""2019.01.06-a\"b""#).unwrap()
+        );
+    }
+
+    #[test]
+    fn object_form() {
+        let version = Version::parse("1!2019.01.06.2-a.1").unwrap();
+
+        assert_eq!(
+            version,
+            serde_json::from_str::<Version>(
+                r#"{"epoch":1,"year":2019,"month":1,"day":6,"changeset":2,"label":"a.1"}"#
+            )
+            .unwrap()
+        );
+        assert_eq!(
+            Version::parse("2019.01.06").unwrap(),
+            serde_json::from_reader::<_, Version>(&br#"{"year":2019,"month":1,"day":6}"#[..])
+                .unwrap()
+        );
+
+        for invalid in [
+            r#"{"year":2019,"month":13,"day":6}"#,
+            r#"{"year":2019,"month":1}"#,
+            "[2019,1,6]",
+        ] {
+            assert!(
+                serde_json::from_str::<Version>(invalid).is_err(),
+                "{invalid}"
+            );
+        }
+    }
+
+    #[test]
+    fn string_only() {
+        /// Struct with a field that is always a string.
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Release {
+            /// The version.
+            #[serde(with = "string")]
+            version: Version,
+        }
+
+        assert!(
+            serde_json::from_str::<Release>(r#"{"version":{"year":2019,"month":1,"day":6}}"#)
+                .is_err()
+        );
+        assert_eq!(
+            Release {
+                version: Version::parse("2019.01.06").unwrap()
+            },
+            serde_json::from_str(r#"{"version":"2019.01.06"}"#).unwrap()
+        );
+    }
+
+    #[test]
+    fn field_helpers() {
+        /// Struct with a field for every helper.
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        struct Release {
+            /// Optional version.
+            #[serde(with = "option_string")]
+            previous: Option<Version>,
+            /// Packed version.
+            #[serde(with = "packed")]
+            packed: Version,
+        }
+
+        let release = Release {
+            previous: Some(Version::parse("2019.01.06-a").unwrap()),
+            packed: Version::parse("2019.01.06.1").unwrap(),
+        };
+        let json = serde_json::to_string(&release).unwrap();
+        assert_eq!(release, serde_json::from_str(&json).unwrap());
+
+        assert_eq!(
+            Release {
+                previous: None,
+                packed: Version::parse("2019.01.06.1").unwrap(),
+            },
+            serde_json::from_str(r#"{"previous":null,"packed":26430223717236737}"#).unwrap()
+        );
+        assert!(serde_json::from_str::<Release>(r#"{"previous":null,"packed":0}"#).is_err());
+        assert!(serde_json::from_str::<Release>(r#"{"previous":1,"packed":1}"#).is_err());
+
+        let release = Release {
+            previous: None,
+            packed: Version::parse("2019.01.06.1-a").unwrap(),
+        };
+        assert_eq!(
+            "versions with label can't be packed",
+            serde_json::to_string(&release).unwrap_err().to_string()
+        );
+    }
+
+    /// Deserializer that forwards to another one, but claims to not be human-readable.
+    struct NotReadable<D>(D);
+
+    impl<'de, D: Deserializer<'de>> Deserializer<'de> for NotReadable<D> {
+        type Error = D::Error;
+
+        fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Self::Error> {
+            self.0.deserialize_any(visitor)
+        }
+
+        fn is_human_readable(&self) -> bool {
+            false
+        }
+
+        serde::forward_to_deserialize_any! {
+            bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string bytes byte_buf
+            option unit unit_struct newtype_struct seq tuple tuple_struct map struct enum
+            identifier ignored_any
+        }
+    }
+
+    #[test]
+    fn compact_form() {
+        let compact = |input: &str| {
+            let mut de = serde_json::Deserializer::from_str(input);
+            Version::deserialize(NotReadable(&mut de))
+        };
+
+        for input in ["2019.01.06", "1!2019.01.06.2-a.1", "2019.01.06-break"] {
+            let version = Version::parse(input).unwrap();
+            let json = serde_json::to_string(&CompactRef::from(&version)).unwrap();
+
+            assert_eq!(version, compact(&json).unwrap(), "{json}");
+        }
+
+        assert_eq!(
+            r#"[1,2019,1,6,2,"a.1"]"#,
+            serde_json::to_string(&CompactRef::from(
+                &Version::parse("1!2019.01.06.2-a.1").unwrap()
+            ))
+            .unwrap()
+        );
+        assert_eq!(
+            Version::parse("1!2019.01.06.2-a.1").unwrap(),
+            compact(r#""1!2019.01.06.2-a.1""#).unwrap()
+        );
+        assert!(compact(r#""2019.13.06""#).is_err());
+        assert!(compact("[0,2019,2,29,0,null]").is_err());
+        assert!(compact("[0,2019,1,6]").is_err());
+
+        let mut de = serde_json::Deserializer::from_str("[0,2019,1,6,0,\"a\"]");
+        assert_eq!(
+            "Label `a` is not allowed",
+            StableVersion::deserialize(NotReadable(&mut de))
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn cbor() {
+        /// Encode the value as CBOR.
+        fn encode<T: Serialize + ?Sized>(value: &T) -> Vec<u8> {
+            let mut bytes = Vec::new();
+            ciborium::ser::into_writer(value, &mut bytes).unwrap();
+            bytes
+        }
+
+        /// Struct with a field in the string form of earlier versions.
+        #[derive(Debug, PartialEq, Deserialize)]
+        struct Release {
+            /// The version.
+            #[serde(with = "string")]
+            version: Version,
+        }
+
+        /// The same struct, as written by earlier versions.
+        #[derive(Serialize)]
+        struct OldRelease {
+            /// The version.
+            version: &'static str,
+        }
+
+        for input in ["2019.01.06", "1!2019.01.06.2-a.1", "2019.01.06-break"] {
+            let version = Version::parse(input).unwrap();
+            let bytes = encode(&version);
+
+            // CBOR is not human-readable, so the version is written as array of six items.
+            assert_eq!(0x86, bytes[0], "{input}");
+            assert_eq!(
+                version,
+                ciborium::de::from_reader::<Version, _>(&bytes[..]).unwrap()
+            );
+        }
+
+        // The string form of earlier versions needs the `string` module.
+        let bytes = encode("1!2019.01.06.2-a.1");
+        assert!(ciborium::de::from_reader::<Version, _>(&bytes[..]).is_err());
+
+        let bytes = encode(&OldRelease {
+            version: "1!2019.01.06.2-a.1",
+        });
+        assert_eq!(
+            Release {
+                version: Version::parse("1!2019.01.06.2-a.1").unwrap()
+            },
+            ciborium::de::from_reader(&bytes[..]).unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let err = serde_json::from_str::<Version>(r#""2019.13.06""#).unwrap_err();
+        assert_eq!(
+            "Invalid version string at line 1 column 12",
+            err.to_string()
+        );
+
+        let err = serde_json::from_str::<StableVersion>(r#""2019.01.06-a""#).unwrap_err();
+        assert_eq!("Label `a` is not allowed", err.to_string());
+
+        let err = serde_json::from_str::<Version>("1").unwrap_err();
+        assert_eq!(
+            "invalid type: integer `1`, expected a version string or object at line 1 column 1",
+            err.to_string()
+        );
+    }
+}
@@ -0,0 +1,431 @@
+//! Ordered collection of versions.
+
+use std::{
+    collections::{btree_set, BTreeSet},
+    ops::{Bound, RangeBounds},
+};
+
+use time::Date;
+
+use crate::{OrderingPolicy, Version};
+
+/// An ordered set of versions, with queries tailored to release tracking.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{Version, VersionSet};
+///
+/// let mut set = VersionSet::new();
+/// set.insert(Version::parse("2020.01.06").unwrap());
+/// set.insert(Version::parse("2020.01.06.1-beta").unwrap());
+///
+/// assert_eq!(set.latest().unwrap().to_string(), "2020.01.06.1-beta");
+/// assert_eq!(set.latest_stable().unwrap().to_string(), "2020.01.06");
+/// ```
+#[derive(Debug, Clone, Default, Eq, PartialEq, Hash)]
+pub struct VersionSet {
+    /// The versions, ordered from oldest to newest.
+    versions: BTreeSet<Version>,
+}
+
+impl VersionSet {
+    /// Create a new, empty set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::VersionSet;
+    ///
+    /// assert!(VersionSet::new().is_empty());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            versions: BTreeSet::new(),
+        }
+    }
+
+    /// Add a version to the set, returning whether it was not present yet.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    ///
+    /// assert!(set.insert(Version::parse("2020.01.06").unwrap()));
+    /// assert!(!set.insert(Version::parse("2020.01.06").unwrap()));
+    /// ```
+    pub fn insert(&mut self, version: Version) -> bool {
+        self.versions.insert(version)
+    }
+
+    /// Remove a version from the set, returning whether it was present.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let version = Version::parse("2020.01.06").unwrap();
+    /// let mut set = VersionSet::new();
+    /// set.insert(version.clone());
+    ///
+    /// assert!(set.remove(&version));
+    /// assert!(set.is_empty());
+    /// ```
+    pub fn remove(&mut self, version: &Version) -> bool {
+        self.versions.remove(version)
+    }
+
+    /// Check whether the set contains the given version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let version = Version::parse("2020.01.06").unwrap();
+    /// let mut set = VersionSet::new();
+    /// set.insert(version.clone());
+    ///
+    /// assert!(set.contains(&version));
+    /// ```
+    #[must_use]
+    pub fn contains(&self, version: &Version) -> bool {
+        self.versions.contains(version)
+    }
+
+    /// Get the amount of versions in the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    ///
+    /// assert_eq!(set.len(), 1);
+    /// ```
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.versions.len()
+    }
+
+    /// Check whether the set contains no versions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::VersionSet;
+    ///
+    /// assert!(VersionSet::new().is_empty());
+    /// ```
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.versions.is_empty()
+    }
+
+    /// Iterate over all versions, from oldest to newest.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.07").unwrap());
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    ///
+    /// let versions = set.iter().map(ToString::to_string).collect::<Vec<_>>();
+    /// assert_eq!(versions, ["2020.01.06", "2020.01.07"]);
+    /// ```
+    pub fn iter(&self) -> btree_set::Iter<'_, Version> {
+        self.versions.iter()
+    }
+
+    /// Get the newest version of the set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.07").unwrap());
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    ///
+    /// assert_eq!(set.latest().unwrap().to_string(), "2020.01.07");
+    /// ```
+    #[must_use]
+    pub fn latest(&self) -> Option<&Version> {
+        self.versions.iter().next_back()
+    }
+
+    /// Get the newest version of the set that doesn't carry a label.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    /// set.insert(Version::parse("2020.01.07-rc").unwrap());
+    ///
+    /// assert_eq!(set.latest_stable().unwrap().to_string(), "2020.01.06");
+    /// ```
+    #[must_use]
+    pub fn latest_stable(&self) -> Option<&Version> {
+        self.versions.iter().rev().find(|v| v.label.is_none())
+    }
+
+    /// Get the newest version of the set, according to the given ordering policy. If several
+    /// versions are equal under the policy, the greatest of them in the regular ordering is
+    /// returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{OrderingPolicy, Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    /// set.insert(Version::parse("2020.01.07-break").unwrap());
+    /// set.insert(Version::parse("2020.01.08-login").unwrap());
+    ///
+    /// let latest = |policy| set.latest_by(policy).unwrap().to_string();
+    /// assert_eq!(latest(OrderingPolicy::Strict), "2020.01.08-login");
+    /// assert_eq!(latest(OrderingPolicy::Channel), "2020.01.06");
+    /// ```
+    #[must_use]
+    pub fn latest_by(&self, policy: OrderingPolicy) -> Option<&Version> {
+        match policy {
+            OrderingPolicy::Strict => self.latest(),
+            _ => self.versions.iter().max_by(|a, b| policy.compare(a, b)),
+        }
+    }
+
+    /// Iterate over all versions that were released within the given range of dates. Only
+    /// versions of the default epoch 0 are included, as other epochs sort after all of them,
+    /// even if the range has no end.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    /// use time::macros::date;
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    /// set.insert(Version::parse("2020.01.07.2-rc").unwrap());
+    /// set.insert(Version::parse("2020.01.08").unwrap());
+    ///
+    /// let versions = set
+    ///     .range_by_date(date!(2020-01-06)..=date!(2020-01-07))
+    ///     .map(ToString::to_string)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(versions, ["2020.01.06", "2020.01.07.2-rc"]);
+    /// ```
+    pub fn range_by_date(&self, range: impl RangeBounds<Date>) -> btree_set::Range<'_, Version> {
+        let start = match range.start_bound() {
+            Bound::Included(&date) => Bound::Included(Version::from(date)),
+            Bound::Excluded(date) => match date.next_day() {
+                Some(date) => Bound::Included(Version::from(date)),
+                None => return self.empty_range(),
+            },
+            Bound::Unbounded => Bound::Unbounded,
+        };
+        // The first version of the next epoch, to keep other epochs out of open-ended ranges.
+        let next_epoch = || Version {
+            epoch: 1,
+            ..Version::from(Date::MIN)
+        };
+        let end = match range.end_bound() {
+            Bound::Included(date) => {
+                Bound::Excluded(date.next_day().map_or_else(next_epoch, Version::from))
+            }
+            Bound::Excluded(&date) => Bound::Excluded(Version::from(date)),
+            Bound::Unbounded => Bound::Excluded(next_epoch()),
+        };
+
+        if let (Bound::Included(start), Bound::Excluded(end)) = (&start, &end) {
+            if start >= end {
+                return self.empty_range();
+            }
+        }
+
+        self.versions.range((start, end))
+    }
+
+    /// Iterate over all versions that fulfill the given requirement.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let mut set = VersionSet::new();
+    /// set.insert(Version::parse("2020.01.06").unwrap());
+    /// set.insert(Version::parse("2020.01.06.1-break").unwrap());
+    ///
+    /// let breaking = set.matching(Version::is_breaking).collect::<Vec<_>>();
+    /// assert_eq!(breaking.len(), 1);
+    /// ```
+    pub fn matching<'a>(
+        &'a self,
+        mut requirement: impl FnMut(&Version) -> bool + 'a,
+    ) -> impl Iterator<Item = &'a Version> + 'a {
+        self.versions.iter().filter(move |v| requirement(v))
+    }
+
+    /// Create a range iterator that yields no elements.
+    fn empty_range(&self) -> btree_set::Range<'_, Version> {
+        let version = Version::from(Date::MIN);
+        self.versions
+            .range((Bound::Included(version.clone()), Bound::Excluded(version)))
+    }
+}
+
+impl FromIterator<Version> for VersionSet {
+    /// Collect versions into a set. Duplicates are removed and the versions get ordered, no
+    /// matter the order in which they are produced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionSet};
+    ///
+    /// let set = ["2020.01.07", "2020.01.06", "2020.01.07"]
+    ///     .iter()
+    ///     .map(|v| v.parse())
+    ///     .collect::<Result<VersionSet, _>>()
+    ///     .unwrap();
+    ///
+    /// assert_eq!(set.len(), 2);
+    /// ```
+    fn from_iter<T: IntoIterator<Item = Version>>(iter: T) -> Self {
+        Self {
+            versions: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Extend<Version> for VersionSet {
+    fn extend<T: IntoIterator<Item = Version>>(&mut self, iter: T) {
+        self.versions.extend(iter);
+    }
+}
+
+impl<'a> Extend<&'a Version> for VersionSet {
+    fn extend<T: IntoIterator<Item = &'a Version>>(&mut self, iter: T) {
+        self.versions.extend(iter.into_iter().cloned());
+    }
+}
+
+impl<'a> IntoIterator for &'a VersionSet {
+    type Item = &'a Version;
+    type IntoIter = btree_set::Iter<'a, Version>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for VersionSet {
+    type Item = Version;
+    type IntoIter = btree_set::IntoIter<Version>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.versions.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    fn set(versions: &[&str]) -> VersionSet {
+        let mut set = VersionSet::new();
+        for v in versions {
+            set.insert(Version::parse(v).unwrap());
+        }
+        set
+    }
+
+    #[test]
+    fn collect_and_extend() {
+        let mut set = ["2020.01.06", "2020.01.05", "2020.01.06"]
+            .iter()
+            .map(|v| Version::parse(v).unwrap())
+            .collect::<VersionSet>();
+        assert_eq!(2, set.len());
+
+        let other = Version::parse("2020.01.07").unwrap();
+        set.extend(std::slice::from_ref(&other));
+        set.extend(vec![other]);
+        assert_eq!(3, set.len());
+        assert_eq!("2020.01.05", set.iter().next().unwrap().to_string());
+    }
+
+    #[test]
+    fn latest() {
+        let set = set(&["2020.01.06", "2020.01.06.3", "2020.01.07-beta"]);
+        assert_eq!("2020.01.07-beta", set.latest().unwrap().to_string());
+        assert_eq!("2020.01.06.3", set.latest_stable().unwrap().to_string());
+        assert_eq!(None, VersionSet::new().latest());
+    }
+
+    #[test]
+    fn range_by_date() {
+        let set = set(&["2020.01.05", "2020.01.06.2", "2020.01.07-a", "2020.01.08"]);
+        let collect = |range: btree_set::Range<'_, Version>| {
+            range.map(ToString::to_string).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ["2020.01.06.2", "2020.01.07-a"],
+            collect(set.range_by_date(date!(2020 - 01 - 06)..date!(2020 - 01 - 08))).as_slice()
+        );
+        assert_eq!(
+            ["2020.01.07-a", "2020.01.08"],
+            collect(set.range_by_date(date!(2020 - 01 - 07)..)).as_slice()
+        );
+        assert_eq!(
+            ["2020.01.05"],
+            collect(set.range_by_date(..=date!(2020 - 01 - 05))).as_slice()
+        );
+        assert!(
+            collect(set.range_by_date(date!(2020 - 01 - 08)..date!(2020 - 01 - 06))).is_empty()
+        );
+        assert!(
+            collect(set.range_by_date((Bound::Excluded(Date::MAX), Bound::Unbounded))).is_empty()
+        );
+    }
+
+    #[test]
+    fn range_by_date_skips_other_epochs() {
+        let set = set(&["2020.01.06", "9999.12.31-last", "1!2019.01.01"]);
+        let collect = |range: btree_set::Range<'_, Version>| {
+            range.map(ToString::to_string).collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            ["2020.01.06", "9999.12.31-last"],
+            collect(set.range_by_date(date!(2020 - 01 - 01)..)).as_slice()
+        );
+        assert_eq!(
+            ["2020.01.06", "9999.12.31-last"],
+            collect(set.range_by_date(..)).as_slice()
+        );
+        assert_eq!(
+            ["9999.12.31-last"],
+            collect(set.range_by_date(date!(9999 - 12 - 31)..=Date::MAX)).as_slice()
+        );
+    }
+}
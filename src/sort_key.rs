@@ -0,0 +1,205 @@
+//! String keys for versions, that sort byte-wise in the same order as the versions.
+//!
+//! The key starts with the zero-padded epoch, year (offset by `1000000`, so negative years stay
+//! in order), month, day and changeset, separated by dots. It's followed by the label, if any:
+//!
+//! - A text label is appended as `-0` and the text.
+//! - A feature label is appended as `-1`, the branch terminated by two NUL characters, and the
+//!   zero-padded changeset. NUL characters in the branch are escaped as NUL followed by `\x01`.
+
+use std::convert::TryFrom;
+
+use time::{Date, Month};
+
+use crate::{ChronVerError, Label, Version};
+
+/// Offset that is added to the year, to keep negative years in order.
+const YEAR_OFFSET: i32 = 1_000_000;
+/// Terminator of the branch in feature labels.
+const BRANCH_END: &str = "\0\0";
+/// Escaped form of NUL characters in branch names.
+const ESCAPED_NUL: &str = "\0\x01";
+
+impl Version {
+    /// Create a zero-padded string key, whose byte-wise ordering matches the ordering of the
+    /// versions. This allows to use versions as keys in key-value stores or as object prefixes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.3-beta").unwrap();
+    /// let key = version.to_sort_key();
+    ///
+    /// assert_eq!(key, "0000000000.1002020.01.06.0000000003-0beta");
+    /// assert_eq!(Version::from_sort_key(&key).unwrap(), version);
+    /// assert!(Version::parse("2020.01.10").unwrap().to_sort_key() > key);
+    /// ```
+    #[must_use]
+    pub fn to_sort_key(&self) -> String {
+        let key = format!(
+            "{:010}.{:07}.{:02}.{:02}.{:010}",
+            self.epoch,
+            self.date.year() + YEAR_OFFSET,
+            u8::from(self.date.month()),
+            self.date.day(),
+            self.changeset
+        );
+
+        match &self.label {
+            None => key,
+            Some(Label::Text(text)) => format!("{key}-0{text}"),
+            Some(Label::Feature { branch, changeset }) => format!(
+                "{key}-1{}{BRANCH_END}{changeset:010}",
+                branch.replace('\0', ESCAPED_NUL)
+            ),
+        }
+    }
+
+    /// Parse a key that was created by [`to_sort_key`](Self::to_sort_key) back into a version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("1!2020.01.06-login.2").unwrap();
+    /// assert_eq!(Version::from_sort_key(&version.to_sort_key()).unwrap(), version);
+    ///
+    /// assert!(Version::from_sort_key("2020.01.06").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the key is not in the format of a sort key, or contains an
+    /// invalid date.
+    pub fn from_sort_key(key: &str) -> Result<Self, ChronVerError> {
+        /// Parse a number of the given width at the start of the input, followed by the
+        /// separator.
+        fn number<'a, T: TryFrom<u64>>(
+            input: &'a str,
+            width: usize,
+            separator: &str,
+        ) -> Result<(T, &'a str), ChronVerError> {
+            let digits = input.get(..width).ok_or(ChronVerError::InvalidSortKey)?;
+            let rest = input[width..]
+                .strip_prefix(separator)
+                .ok_or(ChronVerError::InvalidSortKey)?;
+
+            if !digits.bytes().all(|b| b.is_ascii_digit()) {
+                return Err(ChronVerError::InvalidSortKey);
+            }
+            let value = digits
+                .parse::<u64>()
+                .map_err(|_| ChronVerError::InvalidSortKey)?;
+
+            T::try_from(value)
+                .map(|value| (value, rest))
+                .map_err(|_| ChronVerError::InvalidSortKey)
+        }
+
+        let (epoch, rest) = number(key, 10, ".")?;
+        let (year, rest) = number::<i32>(rest, 7, ".")?;
+        let (month, rest) = number::<u8>(rest, 2, ".")?;
+        let (day, rest) = number(rest, 2, ".")?;
+        let (changeset, rest) = number(rest, 10, "")?;
+
+        let date = Date::from_calendar_date(year - YEAR_OFFSET, Month::try_from(month)?, day)?;
+
+        let label = if rest.is_empty() {
+            None
+        } else if let Some(text) = rest.strip_prefix("-0") {
+            Some(Label::Text(text.to_owned()))
+        } else if let Some(feature) = rest.strip_prefix("-1") {
+            let (branch, changeset) = feature
+                .rsplit_once(BRANCH_END)
+                .ok_or(ChronVerError::InvalidSortKey)?;
+            let (changeset, rest) = number(changeset, 10, "")?;
+            if !rest.is_empty() {
+                return Err(ChronVerError::InvalidSortKey);
+            }
+
+            Some(Label::Feature {
+                branch: branch.replace(ESCAPED_NUL, "\0"),
+                changeset,
+            })
+        } else {
+            return Err(ChronVerError::InvalidSortKey);
+        };
+
+        Ok(Self {
+            epoch,
+            date,
+            changeset,
+            label,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn order_matches() {
+        let versions = [
+            "2019.01.06",
+            "2019.01.06-",
+            "2019.01.06-a",
+            "2019.01.06-a b",
+            "2019.01.06-ab",
+            "2019.01.06-a.1",
+            "2019.01.06-a-b.1",
+            "2019.01.06-a.b.1",
+            "2019.01.06.1",
+            "2019.01.06.10",
+            "2019.01.07",
+            "9999.12.31.4294967295",
+            "1!2019.01.06",
+        ]
+        .iter()
+        .map(|v| Version::parse(v).unwrap())
+        .chain(
+            ["a", "a\0", "a\0\0", "a\0b", "a\x01", "ab"]
+                .iter()
+                .map(|branch| Version {
+                    label: Some(Label::Feature {
+                        branch: (*branch).to_owned(),
+                        changeset: 1,
+                    }),
+                    ..Version::parse("2020.01.01").unwrap()
+                }),
+        )
+        .chain(std::iter::once(Version::from(Date::MIN)))
+        .collect::<Vec<_>>();
+
+        for a in &versions {
+            let key = a.to_sort_key();
+            assert_eq!(a, &Version::from_sort_key(&key).unwrap(), "{key:?}");
+
+            for b in &versions {
+                assert_eq!(a.cmp(b), key.cmp(&b.to_sort_key()), "{a:?} / {b:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn invalid() {
+        let key = Version::parse("2019.01.06-a.1").unwrap().to_sort_key();
+
+        for invalid in [
+            "",
+            &key[..34],
+            &key[..key.len() - 1],
+            "0000000000.1002019.01.06.000000000x",
+            "0000000000.1002019.13.06.0000000000",
+            "0000000000.1002019.01.06.0000000000-2a",
+            "0000000000.1002019.01.06.0000000000-1a",
+            "0000000000.1002019.01.06.0000000000-1a\x00\x000000000001x",
+            "4294967296.1002019.01.06.0000000000",
+        ] {
+            assert!(Version::from_sort_key(invalid).is_err(), "{invalid:?}");
+        }
+    }
+}
@@ -0,0 +1,207 @@
+//! Parse errors together with the location in the input that caused them.
+
+use std::ops::Range;
+
+use thiserror::Error;
+
+use crate::{ChronVerError, ParseOptions, Version};
+
+/// An error from parsing a version, together with the byte range of the input that caused it.
+/// This allows tooling to point exactly at the wrong part, like the month in `2020.13.01`.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::Version;
+///
+/// let input = "2020.13.01";
+/// let err = Version::parse_spanned(input).unwrap_err();
+///
+/// assert_eq!(err.span(), 5..7);
+/// assert_eq!(&input[err.span()], "13");
+/// ```
+#[derive(Error, Debug, Clone, Eq, PartialEq)]
+#[error("{error} at {}..{}", span.start, span.end)]
+pub struct SpannedError {
+    /// The underlying error.
+    #[source]
+    error: ChronVerError,
+    /// Byte range of the input that caused the error.
+    span: Range<usize>,
+    /// The part of the input that caused the error.
+    fragment: String,
+}
+
+impl SpannedError {
+    /// Create a new error with the given span.
+    pub(crate) const fn new(error: ChronVerError, span: Range<usize>) -> Self {
+        Self {
+            error,
+            span,
+            fragment: String::new(),
+        }
+    }
+
+    /// Keep a copy of the part of the input that the span points to.
+    fn with_fragment(self, input: &str) -> Self {
+        Self {
+            fragment: input.get(self.span.clone()).unwrap_or_default().to_owned(),
+            ..self
+        }
+    }
+
+    /// Get the underlying error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, Version};
+    ///
+    /// let err = Version::parse_spanned("2020").unwrap_err();
+    /// assert_eq!(err.error(), &ChronVerError::TooShort);
+    /// ```
+    #[must_use]
+    pub const fn error(&self) -> &ChronVerError {
+        &self.error
+    }
+
+    /// Get the byte range of the input that caused the error. The range may be empty, if
+    /// something is missing at its position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let input = "2020.01.06.x";
+    /// let err = Version::parse_spanned(input).unwrap_err();
+    ///
+    /// assert_eq!(&input[err.span()], "x");
+    /// ```
+    #[must_use]
+    pub fn span(&self) -> Range<usize> {
+        self.span.clone()
+    }
+
+    /// Get the part of the input that caused the error, like the `30` in `2019.30.01`. This is
+    /// the input at the [`span`](Self::span), so it is empty for empty spans.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let err = Version::parse_spanned("2019.30.01").unwrap_err();
+    /// assert_eq!(err.fragment(), "30");
+    ///
+    /// let err = Version::parse_spanned("2019.01.06.1+a").unwrap_err();
+    /// assert_eq!(err.fragment(), "+a");
+    /// ```
+    #[must_use]
+    pub fn fragment(&self) -> &str {
+        &self.fragment
+    }
+
+    /// Discard the span and get the underlying error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{ChronVerError, Version};
+    ///
+    /// let err = Version::parse_spanned("2020.01.06+a").unwrap_err();
+    /// assert_eq!(err.into_error(), ChronVerError::InvalidLabel);
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_error(self) -> ChronVerError {
+        self.error
+    }
+}
+
+impl From<SpannedError> for ChronVerError {
+    #[inline]
+    fn from(error: SpannedError) -> Self {
+        error.into_error()
+    }
+}
+
+impl Version {
+    /// Parse a string into a version like [`Version::parse`], but return errors together with
+    /// the byte range of the offending part of the input.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// assert!(Version::parse_spanned("2020.01.06").is_ok());
+    ///
+    /// let input = "2020.02.30.1";
+    /// let err = Version::parse_spanned(input).unwrap_err();
+    /// assert_eq!(&input[err.span()], "30");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur.
+    pub fn parse_spanned(version: &str) -> Result<Self, SpannedError> {
+        Self::parse_spanned_with(version, &ParseOptions::default())
+            .map_err(|e| e.with_fragment(version))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::LabelCharset;
+
+    #[test]
+    fn spans() {
+        for (input, fragment) in [
+            ("2019", "2019"),
+            ("x!2019.01.06", "x!20"),
+            ("99999999999!2019.01.06", "99999999999"),
+            ("2019.13.06", "13"),
+            ("2019.1x.06", "1x"),
+            ("2019.01.32", "32"),
+            ("2019.02.29", "29"),
+            ("1!abcd.01.06", "abcd"),
+            ("2019-01.06", "-"),
+            ("2019.01/06", "/"),
+            ("2019.01.06.", ""),
+            ("2019.01.06.99999999999", "99999999999"),
+            ("2019.01.06.ü", "ü"),
+            ("2019.01.06+a", "+a"),
+            ("2019.01.06.1+a", "+a"),
+            ("2019.01.0ü", "0ü"),
+            ("2019.0ü.06", "0ü"),
+            ("2019ü01.06", "ü"),
+        ] {
+            let err = Version::parse_spanned(input).unwrap_err();
+            assert_eq!(fragment, &input[err.span()], "{input:?}: {err}");
+            assert_eq!(fragment, err.fragment());
+            assert_eq!(Version::parse(input), Err(err.into_error()));
+        }
+    }
+
+    #[test]
+    fn spans_with_options() {
+        let options = ParseOptions::new()
+            .strict(true)
+            .label_charset(LabelCharset::Portable)
+            .changeset_offset(5)
+            .allowed_labels(["a"]);
+        let span = |input: &str| {
+            Version::parse_spanned_with(input, &options)
+                .unwrap_err()
+                .span()
+        };
+
+        assert_eq!(11..12, span("2019.01.06.4"));
+        assert_eq!(13..14, span("2019.01.06.5-A"));
+        assert_eq!(13..15, span("2019.01.06.5-ab.1"));
+        assert_eq!(11..12, span("2019.01.06.0-a"));
+        assert!(Version::parse_spanned_with("2019.01.06-a", &options).is_ok());
+    }
+}
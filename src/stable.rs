@@ -0,0 +1,154 @@
+//! Versions that are guaranteed to be stable releases.
+
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+    ops::Deref,
+    str::FromStr,
+};
+
+use crate::{ChronVerError, Version};
+
+/// A [`Version`] without label, so APIs that must never accept feature or breaking builds can
+/// require it in their signatures.
+///
+/// # Examples
+///
+/// ```
+/// use std::convert::TryFrom;
+///
+/// use chronver::{ChronVerError, StableVersion, Version};
+///
+/// let stable = StableVersion::try_from(Version::parse("2020.01.06.1").unwrap()).unwrap();
+/// assert_eq!(stable.changeset, 1);
+///
+/// assert_eq!(
+///     "2020.01.06-break".parse::<StableVersion>(),
+///     Err(ChronVerError::LabelNotAllowed("break".to_owned()))
+/// );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct StableVersion(Version);
+
+impl StableVersion {
+    /// Parse a string into a stable version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::StableVersion;
+    ///
+    /// assert!(StableVersion::parse("2020.01.06").is_ok());
+    /// assert!(StableVersion::parse("2020.01.06-beta").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur. In addition,
+    /// [`ChronVerError::LabelNotAllowed`] is returned if the version has a label.
+    pub fn parse(version: &str) -> Result<Self, ChronVerError> {
+        Version::parse(version).and_then(Self::try_from)
+    }
+
+    /// Get the underlying version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{StableVersion, Version};
+    ///
+    /// let stable = StableVersion::parse("2020.01.06").unwrap();
+    /// assert_eq!(stable.into_inner(), Version::parse("2020.01.06").unwrap());
+    /// ```
+    #[must_use]
+    #[allow(clippy::missing_const_for_fn)]
+    pub fn into_inner(self) -> Version {
+        self.0
+    }
+}
+
+impl TryFrom<Version> for StableVersion {
+    type Error = ChronVerError;
+
+    fn try_from(version: Version) -> Result<Self, Self::Error> {
+        if let Some(label) = &version.label {
+            return Err(ChronVerError::LabelNotAllowed(label.to_string()));
+        }
+
+        Ok(Self(version))
+    }
+}
+
+impl From<StableVersion> for Version {
+    #[inline]
+    fn from(version: StableVersion) -> Self {
+        version.0
+    }
+}
+
+impl Deref for StableVersion {
+    type Target = Version;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl AsRef<Version> for StableVersion {
+    #[inline]
+    fn as_ref(&self) -> &Version {
+        &self.0
+    }
+}
+
+impl Display for StableVersion {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for StableVersion {
+    type Err = ChronVerError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::parse(s)
+    }
+}
+
+impl TryFrom<&str> for StableVersion {
+    type Error = ChronVerError;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl From<StableVersion> for String {
+    #[inline]
+    fn from(version: StableVersion) -> Self {
+        version.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rejects_labels() {
+        assert_eq!(
+            Err(ChronVerError::LabelNotAllowed("a.1".to_owned())),
+            StableVersion::parse("2019.01.06-a.1")
+        );
+        assert_eq!(
+            Err(ChronVerError::LabelNotAllowed("break".to_owned())),
+            StableVersion::parse("2019.01.06.2-break")
+        );
+        assert_eq!(Err(ChronVerError::TooShort), StableVersion::parse("2019"));
+
+        let stable = StableVersion::parse("1!2019.01.06.2").unwrap();
+        assert_eq!("1!2019.01.06.2", stable.to_string());
+        assert_eq!(1, stable.epoch);
+    }
+}
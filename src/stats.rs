@@ -0,0 +1,113 @@
+//! Statistics over the release history of a project.
+
+use std::{borrow::Borrow, collections::BTreeSet};
+
+use time::Date;
+
+use crate::Version;
+
+/// A period of time without any release.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Gap {
+    /// Date of the last release before the gap.
+    pub start: Date,
+    /// Date of the first release after the gap.
+    pub end: Date,
+}
+
+impl Gap {
+    /// Get the amount of days between the two releases that surround this gap.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::stats::Gap;
+    /// use time::macros::date;
+    ///
+    /// let gap = Gap {
+    ///     start: date!(2020-01-06),
+    ///     end: date!(2020-02-06),
+    /// };
+    /// assert_eq!(gap.days(), 31);
+    /// ```
+    #[must_use]
+    pub fn days(&self) -> i64 {
+        (self.end - self.start).whole_days()
+    }
+}
+
+/// Find all periods that are longer than the given amount of days without any release. The
+/// versions can be given in any order.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{stats, Version};
+/// use time::macros::date;
+///
+/// let versions = ["2020.01.06", "2020.01.08", "2020.03.01.1", "2020.03.02"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let gaps = stats::gaps(&versions, 30);
+///
+/// assert_eq!(gaps.len(), 1);
+/// assert_eq!(gaps[0].start, date!(2020-01-08));
+/// assert_eq!(gaps[0].end, date!(2020-03-01));
+/// ```
+pub fn gaps<I>(versions: I, threshold_days: u32) -> Vec<Gap>
+where
+    I: IntoIterator,
+    I::Item: Borrow<Version>,
+{
+    let dates = versions
+        .into_iter()
+        .map(|v| v.borrow().date)
+        .collect::<BTreeSet<_>>();
+
+    dates
+        .iter()
+        .zip(dates.iter().skip(1))
+        .map(|(&start, &end)| Gap { start, end })
+        .filter(|gap| gap.days() > i64::from(threshold_days))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn no_gaps() {
+        assert!(gaps(Vec::<Version>::new(), 0).is_empty());
+        assert!(gaps(vec![Version::from(date!(2019 - 01 - 06))], 0).is_empty());
+    }
+
+    #[test]
+    fn unordered() {
+        let versions = [
+            Version::from(date!(2019 - 03 - 01)),
+            Version::from(date!(2019 - 01 - 01)),
+            Version::from(date!(2019 - 01 - 11)),
+            Version::from(date!(2019 - 01 - 01)),
+        ];
+
+        assert_eq!(
+            vec![
+                Gap {
+                    start: date!(2019 - 01 - 01),
+                    end: date!(2019 - 01 - 11),
+                },
+                Gap {
+                    start: date!(2019 - 01 - 11),
+                    end: date!(2019 - 03 - 01),
+                },
+            ],
+            gaps(&versions, 9)
+        );
+        assert_eq!(1, gaps(&versions, 10).len());
+    }
+}
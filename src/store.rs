@@ -0,0 +1,318 @@
+//! Storage backends for the current version of a project.
+
+use std::{
+    fs::{self, OpenOptions},
+    io,
+    path::{Path, PathBuf},
+    process,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use thiserror::Error;
+
+use crate::{ChronVerError, Version};
+
+/// An error that occurred while loading or saving a version.
+#[derive(Error, Debug)]
+pub enum StoreError {
+    /// Accessing the underlying storage failed.
+    #[error("Failed to access version store")]
+    Io(#[from] io::Error),
+    /// The stored version could not be parsed.
+    #[error("Invalid stored version")]
+    Parse(#[from] ChronVerError),
+    /// The store is locked by another process or store instance.
+    #[error("Version store is locked")]
+    Locked,
+}
+
+/// A place to keep the current version of a project, like a file or a remote key-value store.
+///
+/// Implementors only need to provide [`load`](Self::load) and [`save`](Self::save), while the
+/// logic to advance the version is shared through [`bump`](Self::bump). Stores that can be used
+/// by several processes at once should also provide [`lock`](Self::lock) and
+/// [`unlock`](Self::unlock), so concurrent bumps don't produce the same version twice.
+pub trait VersionStore {
+    /// Load the current version, or `None` if no version was saved yet.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage can't be accessed or contains an invalid version.
+    fn load(&self) -> Result<Option<Version>, StoreError>;
+
+    /// Save the given version as the current one.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage can't be accessed.
+    fn save(&mut self, version: &Version) -> Result<(), StoreError>;
+
+    /// Acquire exclusive access to the store, which is held until [`unlock`](Self::unlock) is
+    /// called. The default does nothing, which is fine for stores that aren't shared.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`StoreError::Locked`] if the store is already locked, or another error if the
+    /// storage can't be accessed.
+    fn lock(&mut self) -> Result<(), StoreError> {
+        Ok(())
+    }
+
+    /// Release the access acquired with [`lock`](Self::lock). The default does nothing.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the storage can't be accessed.
+    fn unlock(&mut self) -> Result<(), StoreError> {
+        Ok(())
+    }
+
+    /// Advance the current version (see [`Version::update`]) and save it. If no version exists
+    /// yet, the first version for today is created. The store is locked for the whole operation.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{MemoryStore, Version, VersionStore};
+    ///
+    /// let mut store = MemoryStore::default();
+    /// let first = store.bump().unwrap();
+    /// let second = store.bump().unwrap();
+    ///
+    /// assert_eq!(first.changeset, 0);
+    /// assert_eq!(second.changeset, 1);
+    /// assert_eq!(store.load().unwrap(), Some(second));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if locking, loading or saving the version fails.
+    fn bump(&mut self) -> Result<Version, StoreError> {
+        self.lock()?;
+
+        let result = self.load().and_then(|current| {
+            let version = current.map_or_else(Version::default, |mut version| {
+                version.update();
+                version
+            });
+
+            self.save(&version)?;
+            Ok(version)
+        });
+
+        // Always release the lock, but report the first error.
+        let unlocked = self.unlock();
+        let version = result?;
+        unlocked?;
+        Ok(version)
+    }
+}
+
+/// Store that keeps the version in memory, mostly useful for testing.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct MemoryStore {
+    /// The current version.
+    version: Option<Version>,
+}
+
+impl MemoryStore {
+    /// Create a new store, holding the given version.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{MemoryStore, Version, VersionStore};
+    ///
+    /// let version = Version::parse("2020.01.06").unwrap();
+    /// let store = MemoryStore::new(version.clone());
+    ///
+    /// assert_eq!(store.load().unwrap(), Some(version));
+    /// ```
+    #[must_use]
+    pub const fn new(version: Version) -> Self {
+        Self {
+            version: Some(version),
+        }
+    }
+}
+
+impl VersionStore for MemoryStore {
+    fn load(&self) -> Result<Option<Version>, StoreError> {
+        Ok(self.version.clone())
+    }
+
+    fn save(&mut self, version: &Version) -> Result<(), StoreError> {
+        self.version = Some(version.clone());
+        Ok(())
+    }
+}
+
+/// Store that keeps the version as plain text in a local file.
+///
+/// The file is replaced atomically on save, by writing to a uniquely named temporary file next to
+/// it first and then renaming it over the original. Locking creates a `.lock` file next to the
+/// version file, which is removed again on unlock.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct FileStore {
+    /// Location of the version file.
+    path: PathBuf,
+}
+
+impl FileStore {
+    /// Create a new store, that keeps the version in the file at the given path.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::FileStore;
+    ///
+    /// let store = FileStore::new("VERSION");
+    /// assert_eq!(store.path().to_str(), Some("VERSION"));
+    /// ```
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into() }
+    }
+
+    /// Get the location of the version file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::FileStore;
+    ///
+    /// assert!(FileStore::new("VERSION").path().ends_with("VERSION"));
+    /// ```
+    #[must_use]
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Get the location of a file next to the version file, with the given suffix appended.
+    fn sibling(&self, suffix: &str) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(suffix);
+        path.into()
+    }
+}
+
+impl VersionStore for FileStore {
+    fn load(&self) -> Result<Option<Version>, StoreError> {
+        match fs::read_to_string(&self.path) {
+            Ok(content) => Ok(Some(content.trim().parse()?)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save(&mut self, version: &Version) -> Result<(), StoreError> {
+        /// Counter to keep temporary file names unique within the process.
+        static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+        let count = COUNTER.fetch_add(1, Ordering::Relaxed);
+        let tmp = self.sibling(&format!(".{}.{count}.tmp", process::id()));
+
+        if let Err(e) =
+            fs::write(&tmp, format!("{version}\n")).and_then(|()| fs::rename(&tmp, &self.path))
+        {
+            fs::remove_file(&tmp).ok();
+            return Err(e.into());
+        }
+
+        Ok(())
+    }
+
+    fn lock(&mut self) -> Result<(), StoreError> {
+        match OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(self.sibling(".lock"))
+        {
+            Ok(_) => Ok(()),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Err(StoreError::Locked),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn unlock(&mut self) -> Result<(), StoreError> {
+        fs::remove_file(self.sibling(".lock")).map_err(Into::into)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn file_store() {
+        let path = env::temp_dir().join(format!("chronver-store-{}", std::process::id()));
+        let mut store = FileStore::new(&path);
+
+        assert!(store.load().unwrap().is_none());
+
+        let version = Version::parse("2019.01.06.1-test").unwrap();
+        store.save(&version).unwrap();
+        assert_eq!(Some(version), store.load().unwrap());
+
+        let bumped = store.bump().unwrap();
+        assert_eq!(Some(bumped), store.load().unwrap());
+
+        fs::write(&path, "invalid").unwrap();
+        assert!(matches!(store.load(), Err(StoreError::Parse(_))));
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn file_store_lock() {
+        let path = env::temp_dir().join(format!("chronver-lock-{}", process::id()));
+        let mut first = FileStore::new(&path);
+        let mut second = first.clone();
+
+        first.lock().unwrap();
+        assert!(matches!(second.lock(), Err(StoreError::Locked)));
+        assert!(matches!(second.bump(), Err(StoreError::Locked)));
+        first.unlock().unwrap();
+
+        let version = second.bump().unwrap();
+        assert_eq!(Some(version), first.load().unwrap());
+        assert!(first.lock().is_ok());
+        first.unlock().unwrap();
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn concurrent_bumps() {
+        let path = env::temp_dir().join(format!("chronver-concurrent-{}", process::id()));
+
+        let threads = (0..4)
+            .map(|_| {
+                let mut store = FileStore::new(&path);
+                std::thread::spawn(move || {
+                    for _ in 0..10 {
+                        let _ = loop {
+                            match store.bump() {
+                                Err(StoreError::Locked) => std::thread::yield_now(),
+                                result => break result.unwrap(),
+                            }
+                        };
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let version = FileStore::new(&path).load().unwrap().unwrap();
+        fs::remove_file(&path).unwrap();
+
+        // Each of the 40 bumps must be seen by the next one, unless the date changed in between.
+        if version.date == Version::default().date {
+            assert_eq!(39, version.changeset);
+        }
+    }
+}
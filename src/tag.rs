@@ -0,0 +1,222 @@
+//! Helpers to use versions as git tag names and release titles on hosting platforms.
+
+use crate::{util::Projection, ChronVerError, ParseOptions, Version};
+
+/// Maximum length of a tag name in bytes. Tags are stored as files by git, so this stays within
+/// the file name limit of common file systems.
+pub const MAX_TAG_LENGTH: usize = 255;
+
+/// Maximum length of a release title in characters.
+pub const MAX_RELEASE_TITLE_LENGTH: usize = 255;
+
+/// Characters that are never allowed in a git reference name.
+const FORBIDDEN_CHARS: &[char] = &[' ', '~', '^', ':', '?', '*', '[', '\\'];
+/// Suffix that git reserves for its lock files, which is not allowed for any path component.
+/// The check is case-sensitive, the same as in git itself.
+const LOCK_SUFFIX: &str = ".lock";
+
+/// Check whether the given name is a valid git tag name, following the rules of
+/// `git check-ref-format`, and is at most [`MAX_TAG_LENGTH`] bytes long.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::tag;
+///
+/// assert!(tag::is_valid("2020.01.06.1-team/login"));
+/// assert!(!tag::is_valid("2020.01.06-a..b"));
+/// assert!(!tag::is_valid("2020.01.06-what?"));
+/// ```
+#[must_use]
+pub fn is_valid(name: &str) -> bool {
+    !name.is_empty()
+        && name.len() <= MAX_TAG_LENGTH
+        && name != "@"
+        && !name
+            .chars()
+            .any(|c| c.is_ascii_control() || FORBIDDEN_CHARS.contains(&c))
+        && !name.contains("..")
+        && !name.contains("@{")
+        && !name.ends_with('.')
+        && name
+            .split('/')
+            .all(|c| !c.is_empty() && !c.starts_with('.') && !c.ends_with(LOCK_SUFFIX))
+}
+
+impl Version {
+    /// Parse a git tag name into a version, accepting an optional leading `v` or `V`, like in
+    /// `v2020.01.06`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1").unwrap();
+    ///
+    /// assert_eq!(Version::parse_tag("v2020.01.06.1"), Ok(version.clone()));
+    /// assert_eq!(Version::parse_tag("2020.01.06.1"), Ok(version));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur.
+    pub fn parse_tag(tag: &str) -> Result<Self, ChronVerError> {
+        Self::parse_with(tag, &ParseOptions::new().v_prefix(true))
+    }
+
+    /// Render this version as git tag with a leading `v`, like `v2020.01.06`. This is the
+    /// counterpart to [`parse_tag`](Self::parse_tag).
+    ///
+    /// The label is kept as it is, use [`tag_name`](Self::tag_name) if the tag must be valid on
+    /// any platform.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06.1-beta").unwrap();
+    ///
+    /// assert_eq!(version.to_tag(), "v2020.01.06.1-beta");
+    /// assert_eq!(Version::parse_tag(&version.to_tag()), Ok(version));
+    /// ```
+    #[must_use]
+    pub fn to_tag(&self) -> String {
+        format!("v{self}")
+    }
+
+    /// Check whether this version can be used as git tag name as is (see [`tag::is_valid`]).
+    ///
+    /// [`tag::is_valid`]: crate::tag::is_valid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// assert!(Version::parse("2020.01.06-team/login").unwrap().is_valid_tag_name());
+    /// assert!(!Version::parse("2020.01.06-team login").unwrap().is_valid_tag_name());
+    /// ```
+    #[must_use]
+    pub fn is_valid_tag_name(&self) -> bool {
+        is_valid(&self.to_string())
+    }
+
+    /// Render this version as tag name, that is safe to use on any platform. All characters
+    /// except ASCII alphanumerics, `!`, `.`, `_` and `-` are replaced with an underscore (like
+    /// [`Projection::FileName`]), and the result is adjusted to pass [`tag::is_valid`].
+    ///
+    /// [`Projection::FileName`]: crate::util::Projection::FileName
+    /// [`tag::is_valid`]: crate::tag::is_valid
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{tag, Version};
+    ///
+    /// let version = Version::parse("2020.01.06.1-team/new..feature.").unwrap();
+    /// let name = version.tag_name();
+    ///
+    /// assert_eq!(name, "2020.01.06.1-team_new._feature_");
+    /// assert!(tag::is_valid(&name));
+    /// ```
+    #[must_use]
+    pub fn tag_name(&self) -> String {
+        let mut name = Projection::FileName.apply(self);
+        name.truncate(MAX_TAG_LENGTH);
+
+        while name.contains("..") {
+            name = name.replace("..", "._");
+        }
+        if name.ends_with(LOCK_SUFFIX) {
+            let dot = name.len() - LOCK_SUFFIX.len();
+            name.replace_range(dot..=dot, "_");
+        }
+        if name.ends_with('.') {
+            name.pop();
+            name.push('_');
+        }
+
+        name
+    }
+
+    /// Render this version as title for a release, with control characters replaced by spaces
+    /// and cut off at [`MAX_RELEASE_TITLE_LENGTH`] characters.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::Version;
+    ///
+    /// let version = Version::parse("2020.01.06-new\tfeature").unwrap();
+    /// assert_eq!(version.release_title(), "2020.01.06-new feature");
+    /// ```
+    #[must_use]
+    pub fn release_title(&self) -> String {
+        self.to_string()
+            .chars()
+            .map(|c| if c.is_control() { ' ' } else { c })
+            .take(MAX_RELEASE_TITLE_LENGTH)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ref_format_rules() {
+        for name in [
+            "",
+            "@",
+            "2019.01.06-a b",
+            "2019.01.06-a@{1}",
+            "2019.01.06-a/",
+            "2019.01.06-a//b",
+            "2019.01.06-a/.b",
+            "2019.01.06-a.lock",
+            "2019.01.06-a.lock/b",
+            "2019.01.06-\u{7f}",
+        ] {
+            assert!(!is_valid(name), "{name:?} should be invalid");
+        }
+        assert!(is_valid("2019.01.06-a.locked/b_c"));
+    }
+
+    #[test]
+    fn v_prefix() {
+        let version = Version::parse("1!2019.01.06-a.1").unwrap();
+
+        assert_eq!("v1!2019.01.06-a.1", version.to_tag());
+        assert_eq!(Ok(version.clone()), Version::parse_tag("V1!2019.01.06-a.1"));
+        assert_eq!(Ok(version), Version::parse_tag("1!2019.01.06-a.1"));
+
+        for tag in ["vv2019.01.06", "v 2019.01.06", "version2019.01.06", "v"] {
+            assert!(Version::parse_tag(tag).is_err(), "{tag:?}");
+        }
+        assert!(Version::parse("v2019.01.06").is_err());
+    }
+
+    #[test]
+    fn tag_name_is_always_valid() {
+        for label in ["a.lock", "a.", "a...b", "a b:c", "ü"] {
+            let version = Version::parse(&format!("2019.01.06-{label}")).unwrap();
+            assert!(is_valid(&version.tag_name()), "{label}");
+        }
+
+        let version = Version::parse(&format!("2019.01.06-{}", "a".repeat(300))).unwrap();
+        assert_eq!(MAX_TAG_LENGTH, version.tag_name().len());
+    }
+
+    #[test]
+    fn tag_name_keeps_epoch() {
+        let version = Version::parse("2!2019.01.06.3-a").unwrap();
+        let name = version.tag_name();
+
+        assert_eq!("2!2019.01.06.3-a", name);
+        assert!(is_valid(&name));
+        assert_eq!(Ok(version), Version::parse_tag(&name));
+    }
+}
@@ -0,0 +1,124 @@
+//! Helpers for testing code that builds on versions.
+
+use std::{cmp::Ordering, fmt::Debug};
+
+/// Check that the ordering of the given samples follows the laws of a total order.
+///
+/// Besides the [`Ord`] laws, [`PartialOrd`] and [`PartialEq`] must agree with it. This is useful
+/// for types that wrap or extend the ordering of versions.
+///
+/// Every pair and triple of samples is compared, so the amount of comparisons grows cubically
+/// with the amount of samples.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{testing, Descending, Version};
+///
+/// let versions = ["2020.01.06", "2020.01.06.1", "2020.01.06-beta", "2020.01.07"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// testing::check_order_laws(&versions);
+/// testing::check_order_laws(versions.into_iter().map(Descending));
+/// ```
+///
+/// # Panics
+///
+/// Panics with a description of the violated law and the involved samples, if any law doesn't
+/// hold.
+pub fn check_order_laws<I, T>(samples: I)
+where
+    I: IntoIterator<Item = T>,
+    T: Ord + Debug,
+{
+    let samples = samples.into_iter().collect::<Vec<_>>();
+
+    for a in &samples {
+        assert_eq!(a.cmp(a), Ordering::Equal, "reflexivity: {a:?} != itself");
+
+        for b in &samples {
+            let ord = a.cmp(b);
+
+            assert_eq!(
+                ord,
+                b.cmp(a).reverse(),
+                "antisymmetry: {a:?} cmp {b:?} is {ord:?}, but reversed is {:?}",
+                b.cmp(a)
+            );
+            assert_eq!(
+                a.partial_cmp(b),
+                Some(ord),
+                "partial_cmp of {a:?} and {b:?} disagrees with cmp"
+            );
+            assert_eq!(
+                a == b,
+                ord == Ordering::Equal,
+                "eq of {a:?} and {b:?} disagrees with cmp"
+            );
+
+            for c in &samples {
+                if ord != Ordering::Greater && b.cmp(c) != Ordering::Greater {
+                    assert_ne!(
+                        a.cmp(c),
+                        Ordering::Greater,
+                        "transitivity: {a:?} <= {b:?} <= {c:?}, but {a:?} > {c:?}"
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Version;
+
+    /// Type that claims to be equal to everything, but orders by its value.
+    #[derive(Debug)]
+    struct Inconsistent(u8);
+
+    impl PartialEq for Inconsistent {
+        fn eq(&self, _: &Self) -> bool {
+            true
+        }
+    }
+
+    impl Eq for Inconsistent {}
+
+    impl PartialOrd for Inconsistent {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Inconsistent {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    #[test]
+    fn versions_follow_laws() {
+        check_order_laws(
+            [
+                "2019.01.06",
+                "2019.01.06.1",
+                "2019.01.06-a",
+                "2019.01.06-a.1",
+                "2019.01.06-break",
+                "2019.01.07",
+            ]
+            .iter()
+            .map(|v| Version::parse(v).unwrap()),
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "eq of Inconsistent(1) and Inconsistent(2) disagrees with cmp")]
+    fn detects_inconsistent_eq() {
+        check_order_laws([Inconsistent(1), Inconsistent(2)]);
+    }
+}
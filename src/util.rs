@@ -0,0 +1,190 @@
+//! Assorted helpers for release pipelines.
+
+use std::{
+    borrow::Borrow,
+    collections::{BTreeMap, BTreeSet},
+};
+
+use crate::Version;
+
+/// A simplified view of a version, under which different versions may become ambiguous.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum Projection {
+    /// The label is ignored, only the date and changeset are considered.
+    IgnoreLabel,
+    /// The changeset is ignored, only the date and label are considered.
+    IgnoreChangeset,
+    /// The version is rendered for use in file names, replacing all characters except ASCII
+    /// alphanumerics, `!`, `.`, `_` and `-` with an underscore. The `!` of the epoch is kept, so
+    /// versions of different epochs don't collide.
+    FileName,
+}
+
+impl Projection {
+    /// All available projections.
+    pub const ALL: [Self; 3] = [Self::IgnoreLabel, Self::IgnoreChangeset, Self::FileName];
+
+    /// Apply this projection to the version, returning the key that is compared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{util::Projection, Version};
+    ///
+    /// let version = Version::parse("2020.01.06.1-team/login").unwrap();
+    ///
+    /// assert_eq!(Projection::IgnoreLabel.apply(&version), "2020.01.06.1");
+    /// assert_eq!(Projection::IgnoreChangeset.apply(&version), "2020.01.06-team/login");
+    /// assert_eq!(Projection::FileName.apply(&version), "2020.01.06.1-team_login");
+    /// ```
+    #[must_use]
+    pub fn apply(self, version: &Version) -> String {
+        match self {
+            Self::IgnoreLabel => Version {
+                label: None,
+                ..version.clone()
+            }
+            .to_string(),
+            Self::IgnoreChangeset => Version {
+                changeset: 0,
+                ..version.clone()
+            }
+            .to_string(),
+            Self::FileName => version
+                .to_string()
+                .chars()
+                .map(|c| {
+                    if c.is_ascii_alphanumeric() || matches!(c, '!' | '.' | '_' | '-') {
+                        c
+                    } else {
+                        '_'
+                    }
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Two distinct versions that become identical under a [`Projection`].
+#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct Conflict {
+    /// The projection under which both versions collide.
+    pub projection: Projection,
+    /// The older of both versions.
+    pub first: Version,
+    /// The newer of both versions.
+    pub second: Version,
+}
+
+/// Find all pairs of distinct versions that collide under any of the [`Projection`]s. Identical
+/// versions are not reported.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{util, Version};
+///
+/// let versions = ["2020.01.06.1", "2020.01.06.1-beta", "2020.01.07"]
+///     .iter()
+///     .map(|v| Version::parse(v).unwrap())
+///     .collect::<Vec<_>>();
+///
+/// let conflicts = util::find_conflicts(&versions);
+///
+/// assert_eq!(conflicts.len(), 1);
+/// assert_eq!(conflicts[0].first.to_string(), "2020.01.06.1");
+/// assert_eq!(conflicts[0].second.to_string(), "2020.01.06.1-beta");
+/// ```
+pub fn find_conflicts<I>(versions: I) -> Vec<Conflict>
+where
+    I: IntoIterator,
+    I::Item: Borrow<Version>,
+{
+    let versions = versions
+        .into_iter()
+        .map(|v| v.borrow().clone())
+        .collect::<BTreeSet<_>>();
+
+    let mut conflicts = Vec::new();
+
+    for projection in Projection::ALL {
+        let mut groups = BTreeMap::<_, Vec<_>>::new();
+        for version in &versions {
+            groups
+                .entry(projection.apply(version))
+                .or_default()
+                .push(version);
+        }
+
+        for group in groups.values() {
+            for (i, first) in group.iter().enumerate() {
+                for second in &group[i + 1..] {
+                    conflicts.push(Conflict {
+                        projection,
+                        first: (*first).clone(),
+                        second: (*second).clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    conflicts.sort();
+    conflicts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn conflicts(versions: &[&str]) -> Vec<(Projection, String, String)> {
+        find_conflicts(versions.iter().map(|v| Version::parse(v).unwrap()))
+            .into_iter()
+            .map(|c| (c.projection, c.first.to_string(), c.second.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn duplicates_are_ignored() {
+        assert!(conflicts(&["2019.01.06", "2019.01.06"]).is_empty());
+    }
+
+    #[test]
+    fn file_name_keeps_epoch() {
+        let version = Version::parse("1!2019.01.06.1-a").unwrap();
+        let name = Projection::FileName.apply(&version);
+
+        assert_eq!("1!2019.01.06.1-a", name);
+        assert_eq!(Ok(version), Version::parse(&name));
+        assert!(conflicts(&["1!2019.01.06", "2019.01.06", "2!2019.01.06"]).is_empty());
+    }
+
+    #[test]
+    fn all_projections() {
+        assert_eq!(
+            vec![
+                (
+                    Projection::IgnoreLabel,
+                    "2019.01.06.1-a/b".to_owned(),
+                    "2019.01.06.1-a_b".to_owned()
+                ),
+                (
+                    Projection::IgnoreChangeset,
+                    "2019.01.06-x".to_owned(),
+                    "2019.01.06.2-x".to_owned()
+                ),
+                (
+                    Projection::FileName,
+                    "2019.01.06.1-a/b".to_owned(),
+                    "2019.01.06.1-a_b".to_owned()
+                ),
+            ],
+            conflicts(&[
+                "2019.01.06.1-a/b",
+                "2019.01.06.1-a_b",
+                "2019.01.06-x",
+                "2019.01.06.2-x"
+            ])
+        );
+    }
+}
@@ -0,0 +1,263 @@
+//! Policy checks on top of syntactically valid versions.
+
+use std::{
+    fmt::{self, Display},
+    ops::RangeInclusive,
+};
+
+use time::Date;
+
+use crate::Version;
+
+/// Boxed rule function, returning a message if the version violates the rule.
+type Rule = Box<dyn Fn(&Version) -> Option<String> + Send + Sync>;
+
+/// A violated rule, as reported by [`Validator::validate`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Violation {
+    /// Name of the violated rule.
+    pub rule: String,
+    /// Description of the violation.
+    pub message: String,
+}
+
+impl Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: {}", self.rule, self.message)
+    }
+}
+
+/// A set of custom rules that versions are checked against, for example to enforce
+/// organization-specific release policies.
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{Validator, Version};
+///
+/// let validator = Validator::new()
+///     .max_branch_length(8)
+///     .allowed_labels(["break", "hotfix"])
+///     .rule("no-changeset", |v| {
+///         (v.changeset > 0).then(|| "changesets are not allowed".to_owned())
+///     });
+///
+/// assert!(validator.validate(&Version::parse("2020.01.06-hotfix").unwrap()).is_ok());
+///
+/// let violations = validator
+///     .validate(&Version::parse("2020.01.06.1-experiment").unwrap())
+///     .unwrap_err();
+/// assert_eq!(violations.len(), 3);
+/// ```
+#[derive(Default)]
+pub struct Validator {
+    /// All registered rules with their name.
+    rules: Vec<(String, Rule)>,
+}
+
+impl Validator {
+    /// Create a new validator without any rules.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Validator, Version};
+    ///
+    /// assert!(Validator::new().validate(&Version::default()).is_ok());
+    /// ```
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a custom rule. The rule returns a message describing the problem, if the
+    /// version violates it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Validator, Version};
+    ///
+    /// let validator = Validator::new().rule("stable", |v| {
+    ///     v.label.as_ref().map(|l| format!("unexpected label `{l}`"))
+    /// });
+    ///
+    /// let violations = validator
+    ///     .validate(&Version::parse("2020.01.06-beta").unwrap())
+    ///     .unwrap_err();
+    /// assert_eq!(violations[0].to_string(), "stable: unexpected label `beta`");
+    /// ```
+    #[must_use]
+    pub fn rule(
+        mut self,
+        name: impl Into<String>,
+        rule: impl Fn(&Version) -> Option<String> + Send + Sync + 'static,
+    ) -> Self {
+        self.rules.push((name.into(), Box::new(rule)));
+        self
+    }
+
+    /// Limit the length of the label's branch name to the given amount of bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Validator, Version};
+    ///
+    /// let validator = Validator::new().max_branch_length(4);
+    ///
+    /// assert!(validator.validate(&Version::parse("2020.01.06-abcd.1").unwrap()).is_ok());
+    /// assert!(validator.validate(&Version::parse("2020.01.06-abcde").unwrap()).is_err());
+    /// ```
+    #[must_use]
+    pub fn max_branch_length(self, max: usize) -> Self {
+        self.rule("max-branch-length", move |v| {
+            let branch = v.label.as_ref()?.branch();
+            (branch.len() > max).then(|| {
+                format!(
+                    "branch `{branch}` is {} bytes long, but at most {max} are allowed",
+                    branch.len()
+                )
+            })
+        })
+    }
+
+    /// Only allow labels with one of the given branch names. Versions without label are always
+    /// allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Validator, Version};
+    ///
+    /// let validator = Validator::new().allowed_labels(["break"]);
+    ///
+    /// assert!(validator.validate(&Version::parse("2020.01.06").unwrap()).is_ok());
+    /// assert!(validator.validate(&Version::parse("2020.01.06-break").unwrap()).is_ok());
+    /// assert!(validator.validate(&Version::parse("2020.01.06-beta").unwrap()).is_err());
+    /// ```
+    #[must_use]
+    pub fn allowed_labels<I>(self, labels: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let labels = labels.into_iter().map(Into::into).collect::<Vec<String>>();
+
+        self.rule("allowed-labels", move |v| {
+            let branch = v.label.as_ref()?.branch();
+            (!labels.iter().any(|l| l == branch))
+                .then(|| format!("label `{branch}` is not allowed"))
+        })
+    }
+
+    /// Only allow versions with a date inside the given window.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Validator, Version};
+    /// use time::macros::date;
+    ///
+    /// let validator = Validator::new().date_window(date!(2020-01-01)..=date!(2020-12-31));
+    ///
+    /// assert!(validator.validate(&Version::parse("2020.01.06").unwrap()).is_ok());
+    /// assert!(validator.validate(&Version::parse("2021.01.06").unwrap()).is_err());
+    /// ```
+    #[must_use]
+    pub fn date_window(self, window: RangeInclusive<Date>) -> Self {
+        self.rule("date-window", move |v| {
+            (!window.contains(&v.date)).then(|| {
+                format!(
+                    "date {} is outside of {} to {}",
+                    v.date,
+                    window.start(),
+                    window.end()
+                )
+            })
+        })
+    }
+
+    /// Check the version against all rules, returning every violation that was found.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Validator, Version};
+    ///
+    /// let validator = Validator::new().max_branch_length(2);
+    /// assert!(validator.validate(&Version::parse("2020.01.06").unwrap()).is_ok());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns all violated rules, if there is at least one.
+    pub fn validate(&self, version: &Version) -> Result<(), Vec<Violation>> {
+        let violations = self
+            .rules
+            .iter()
+            .filter_map(|(name, rule)| {
+                rule(version).map(|message| Violation {
+                    rule: name.clone(),
+                    message,
+                })
+            })
+            .collect::<Vec<_>>();
+
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(violations)
+        }
+    }
+}
+
+impl fmt::Debug for Validator {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Validator")
+            .field(
+                "rules",
+                &self.rules.iter().map(|(name, _)| name).collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::macros::date;
+
+    use super::*;
+
+    #[test]
+    fn collects_all_violations() {
+        let validator = Validator::new()
+            .max_branch_length(3)
+            .allowed_labels(["abc"])
+            .date_window(date!(2020 - 01 - 01)..=date!(2020 - 12 - 31));
+
+        let violations = validator
+            .validate(&Version::parse("2019.01.06-abcd.1").unwrap())
+            .unwrap_err()
+            .into_iter()
+            .map(|v| v.rule)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            vec!["max-branch-length", "allowed-labels", "date-window"],
+            violations
+        );
+        assert!(validator
+            .validate(&Version::parse("2020.01.06-abc.1").unwrap())
+            .is_ok());
+    }
+
+    #[test]
+    fn debug() {
+        let validator = Validator::new().max_branch_length(3);
+        assert_eq!(
+            "Validator { rules: [\"max-branch-length\"] }",
+            format!("{validator:?}")
+        );
+    }
+}
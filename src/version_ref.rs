@@ -0,0 +1,295 @@
+//! Borrowed versions, that can be parsed without allocating.
+
+use std::{
+    convert::TryFrom,
+    fmt::{self, Display},
+};
+
+use time::Date;
+
+use crate::{
+    check_length, parse_ref, ChronVerError, Label, ParseOptions, SpannedError, Version,
+    BREAK_LABEL, DATE_FORMAT,
+};
+
+/// A version that borrows its label from the parsed input, so parsing it never allocates. This
+/// is useful when parsing large amounts of versions, like from registry indexes.
+///
+/// It parses, orders and displays exactly the same as [`Version`], and can be turned into one
+/// with [`to_version`](Self::to_version).
+///
+/// # Examples
+///
+/// ```
+/// use chronver::{LabelRef, Version, VersionRef};
+///
+/// let input = String::from("2020.01.06.1-login.2");
+/// let version = VersionRef::parse(&input).unwrap();
+///
+/// assert_eq!(version.changeset, 1);
+/// assert_eq!(
+///     version.label,
+///     Some(LabelRef::Feature {
+///         branch: "login",
+///         changeset: 2
+///     })
+/// );
+/// assert_eq!(version.to_string(), input);
+/// assert_eq!(version.to_version(), Version::parse(&input).unwrap());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub struct VersionRef<'a> {
+    /// The epoch of the version, see [`Version::epoch`].
+    pub epoch: u32,
+    /// The date of release, see [`Version::date`].
+    pub date: Date,
+    /// The changeset number, see [`Version::changeset`].
+    pub changeset: u32,
+    /// The borrowed label, see [`Version::label`].
+    pub label: Option<LabelRef<'a>>,
+}
+
+impl<'a> VersionRef<'a> {
+    /// Parse a string into a borrowed version, following the same rules as [`Version::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{LabelRef, VersionRef};
+    ///
+    /// let version = VersionRef::parse("1!2020.01.06-beta").unwrap();
+    ///
+    /// assert_eq!(version.epoch, 1);
+    /// assert_eq!(version.label, Some(LabelRef::Text("beta")));
+    /// assert!(VersionRef::parse("2020.01.06+beta").is_err());
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// The same errors as for [`Version::parse`] can occur.
+    pub fn parse(version: &'a str) -> Result<Self, ChronVerError> {
+        let options = ParseOptions::default();
+        check_length(version, &options)
+            .and_then(|()| parse_ref(version, &options))
+            .map_err(SpannedError::into_error)
+    }
+
+    /// Create an owned version out of this borrowed one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Version, VersionRef};
+    ///
+    /// let version = VersionRef::parse("2020.01.06-beta").unwrap();
+    /// assert_eq!(version.to_version(), Version::parse("2020.01.06-beta").unwrap());
+    /// ```
+    #[must_use]
+    pub fn to_version(&self) -> Version {
+        Version::from(*self)
+    }
+
+    /// Check whether the version introduces breaking changes, like
+    /// [`Version::is_breaking`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::VersionRef;
+    ///
+    /// assert!(VersionRef::parse("2020.01.06-break").unwrap().is_breaking());
+    /// assert!(!VersionRef::parse("2020.01.06").unwrap().is_breaking());
+    /// ```
+    #[must_use]
+    pub fn is_breaking(&self) -> bool {
+        self.label == Some(LabelRef::Text(BREAK_LABEL))
+    }
+}
+
+impl Display for VersionRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.epoch > 0 {
+            write!(f, "{}!", self.epoch)?;
+        }
+        f.write_str(&self.date.format(&DATE_FORMAT).map_err(|_| fmt::Error)?)?;
+        if self.changeset > 0 {
+            write!(f, ".{}", self.changeset)?;
+        }
+        if let Some(label) = &self.label {
+            write!(f, "-{label}")?;
+        }
+        Ok(())
+    }
+}
+
+impl<'a> TryFrom<&'a str> for VersionRef<'a> {
+    type Error = ChronVerError;
+
+    fn try_from(s: &'a str) -> Result<Self, Self::Error> {
+        Self::parse(s)
+    }
+}
+
+impl<'a> From<&'a Version> for VersionRef<'a> {
+    fn from(version: &'a Version) -> Self {
+        Self {
+            epoch: version.epoch,
+            date: version.date,
+            changeset: version.changeset,
+            label: version.label.as_ref().map(LabelRef::from),
+        }
+    }
+}
+
+impl From<VersionRef<'_>> for Version {
+    fn from(version: VersionRef<'_>) -> Self {
+        Self {
+            epoch: version.epoch,
+            date: version.date,
+            changeset: version.changeset,
+            label: version.label.map(Label::from),
+        }
+    }
+}
+
+/// A label that borrows its text, the counterpart to [`Label`] for [`VersionRef`].
+///
+/// # Examples
+///
+/// ```
+/// use chronver::LabelRef;
+///
+/// assert_eq!(LabelRef::parse("beta"), LabelRef::Text("beta"));
+/// assert_eq!(
+///     LabelRef::parse("login.2"),
+///     LabelRef::Feature {
+///         branch: "login",
+///         changeset: 2
+///     }
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum LabelRef<'a> {
+    /// A simple text label, see [`Label::Text`].
+    Text(&'a str),
+    /// A feature label, see [`Label::Feature`].
+    Feature {
+        /// Name of the feature branch.
+        branch: &'a str,
+        /// Changeset number, omitted if 0.
+        changeset: u32,
+    },
+}
+
+impl<'a> LabelRef<'a> {
+    /// Parse a label from the given string, following the same rules as [`Label::parse`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chronver::{Label, LabelRef};
+    ///
+    /// assert_eq!(Label::from(LabelRef::parse("login.2")), Label::parse("login.2"));
+    /// ```
+    #[must_use]
+    pub fn parse(label: &'a str) -> Self {
+        if let Some(i) = label.rfind('.') {
+            if let Ok(changeset) = label[i + 1..].parse() {
+                return Self::Feature {
+                    branch: &label[..i],
+                    changeset,
+                };
+            }
+        }
+
+        Self::Text(label)
+    }
+}
+
+impl Display for LabelRef<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Text(s) => f.write_str(s),
+            Self::Feature { branch, changeset } => write!(f, "{branch}.{changeset}"),
+        }
+    }
+}
+
+impl<'a> From<&'a Label> for LabelRef<'a> {
+    fn from(label: &'a Label) -> Self {
+        match label {
+            Label::Text(text) => Self::Text(text),
+            Label::Feature { branch, changeset } => Self::Feature {
+                branch,
+                changeset: *changeset,
+            },
+        }
+    }
+}
+
+impl From<LabelRef<'_>> for Label {
+    fn from(label: LabelRef<'_>) -> Self {
+        match label {
+            LabelRef::Text(text) => Self::Text(text.to_owned()),
+            LabelRef::Feature { branch, changeset } => Self::Feature {
+                branch: branch.to_owned(),
+                changeset,
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn same_as_owned() {
+        let inputs = [
+            "2019.01.06",
+            "2019.01.06.0",
+            "2019.01.06.12",
+            "2019.01.06-",
+            "2019.01.06-a",
+            "2019.01.06.2-a.1",
+            "2019.01.06-a.b.1",
+            "2019.01.06-break",
+            "1!2019.01.06-ü",
+            "2019",
+            "2019.13.06",
+            "2019.01.06.",
+            "2019.01.06.x",
+            "2019.01.06+a",
+            "2019.01.06.1+a",
+            "2019.01.0ü",
+            "99999999999!2019.01.06",
+        ];
+
+        for input in inputs {
+            let owned = Version::parse(input);
+            let borrowed = VersionRef::parse(input);
+
+            assert_eq!(owned, borrowed.map(Version::from), "{input:?}");
+            if let Ok(owned) = owned {
+                let borrowed = VersionRef::parse(input).unwrap();
+                assert_eq!(owned.to_string(), borrowed.to_string());
+                assert_eq!(owned.is_breaking(), borrowed.is_breaking());
+                assert_eq!(borrowed, VersionRef::from(&owned));
+            }
+        }
+
+        let versions = inputs
+            .iter()
+            .filter_map(|v| Version::parse(v).ok())
+            .collect::<Vec<_>>();
+        for a in &versions {
+            for b in &versions {
+                assert_eq!(
+                    a.cmp(b),
+                    VersionRef::from(a).cmp(&VersionRef::from(b)),
+                    "{a} / {b}"
+                );
+            }
+        }
+    }
+}